@@ -0,0 +1,295 @@
+use crate::backend::archive_service::{ArchiveService, ArchivedProject};
+use crate::backend::get_or_create_project_uuid;
+use crate::backend::ignore_rules::project_walker;
+use crate::backend::relocate::{category_dir, relocate_path_state};
+use crate::backend::timeline::{TimelineEvent, TimelineEventType};
+use crate::backend::timeline_service::TimelineService;
+use crate::backend::watcher;
+use chrono::Utc;
+use ignore::WalkBuilder;
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Emitter, Manager};
+use uuid::Uuid;
+
+const ARCHIVE_EXTENSION: &str = "tar.zst";
+
+pub fn archive_dir() -> Result<PathBuf, String> {
+    let mut path = dirs::data_dir().ok_or("Could not find data directory")?;
+    path.push("project-manager");
+    path.push("archive");
+    fs::create_dir_all(&path).map_err(|e| format!("Failed to create archive dir: {}", e))?;
+    Ok(path)
+}
+
+/// Paths to pack, relative to `root`. `.git` is always kept, even when ignored output is left out.
+fn archive_entries(root: &Path, exclude_ignored: bool) -> Vec<(PathBuf, bool)> {
+    let mut walkers = Vec::new();
+    let mut entries = Vec::new();
+    if exclude_ignored {
+        walkers.push(project_walker(root));
+        // A worktree or submodule has a `.git` file pointing at its repository, which the walker skips too
+        let git_dir = root.join(".git");
+        if git_dir.is_dir() {
+            let mut git = WalkBuilder::new(git_dir);
+            git.standard_filters(false);
+            walkers.push(git);
+        } else if git_dir.exists() {
+            entries.push((PathBuf::from(".git"), false));
+        }
+    } else {
+        let mut all = WalkBuilder::new(root);
+        all.standard_filters(false);
+        walkers.push(all);
+    }
+
+    for walker in walkers {
+        for entry in walker.build().filter_map(|e| e.ok()) {
+            let Ok(relative) = entry.path().strip_prefix(root) else {
+                continue;
+            };
+            if relative.as_os_str().is_empty() {
+                continue;
+            }
+            let is_dir = entry.file_type().map(|t| t.is_dir()).unwrap_or(false);
+            entries.push((relative.to_path_buf(), is_dir));
+        }
+    }
+    entries
+}
+
+/// Write `root` as a zstd-compressed tarball to `target`, via a temporary file
+fn pack(root: &Path, target: &Path, exclude_ignored: bool) -> Result<(), String> {
+    let partial = target.with_extension("partial");
+    let result = (|| {
+        let file = File::create(&partial).map_err(|e| format!("Failed to create archive: {}", e))?;
+        let encoder = zstd::Encoder::new(file, 0).map_err(|e| format!("Failed to start compression: {}", e))?;
+        let mut builder = tar::Builder::new(encoder);
+        builder.follow_symlinks(false);
+        for (relative, is_dir) in archive_entries(root, exclude_ignored) {
+            let path = root.join(&relative);
+            let appended = if is_dir {
+                builder.append_dir(&relative, &path)
+            } else {
+                builder.append_path_with_name(&path, &relative)
+            };
+            appended.map_err(|e| format!("Failed to archive {}: {}", relative.display(), e))?;
+        }
+        builder
+            .into_inner()
+            .and_then(|encoder| encoder.finish())
+            .map_err(|e| format!("Failed to finish archive: {}", e))?;
+        fs::rename(&partial, target).map_err(|e| format!("Failed to save archive: {}", e))
+    })();
+    if result.is_err() {
+        let _ = fs::remove_file(&partial);
+    }
+    result
+}
+
+/// Unpack an archive into `target`, which must not exist yet
+fn unpack(archive: &Path, target: &Path) -> Result<(), String> {
+    let name = target.file_name().unwrap_or_default().to_string_lossy().to_string();
+    let staging = target.with_file_name(format!(".{}.restoring", name));
+    if staging.exists() {
+        fs::remove_dir_all(&staging).map_err(|e| format!("Failed to clear staging dir: {}", e))?;
+    }
+    let result = (|| {
+        fs::create_dir_all(&staging).map_err(|e| format!("Failed to create project directory: {}", e))?;
+        let file = File::open(archive).map_err(|e| format!("Failed to open archive: {}", e))?;
+        let decoder = zstd::Decoder::new(file).map_err(|e| format!("Failed to read archive: {}", e))?;
+        tar::Archive::new(decoder)
+            .unpack(&staging)
+            .map_err(|e| format!("Failed to unpack archive: {}", e))?;
+        fs::rename(&staging, target).map_err(|e| format!("Failed to restore project: {}", e))
+    })();
+    if result.is_err() {
+        let _ = fs::remove_dir_all(&staging);
+    }
+    result
+}
+
+fn record_event(app_handle: &AppHandle, project_id: &str, event_type: TimelineEventType, title: String, tag: &str) {
+    let event = TimelineEvent {
+        id: Uuid::new_v4(),
+        project_id: Uuid::parse_str(project_id).unwrap_or_else(|_| Uuid::new_v4()),
+        timestamp: Utc::now(),
+        event_type,
+        title,
+        description: None,
+        metadata: HashMap::new(),
+        user_id: None,
+        tags: vec![tag.to_string()],
+    };
+
+    let _ = app_handle.emit("timeline_event_added", &event);
+    if let Some(timeline_service) = app_handle.try_state::<TimelineService>() {
+        let _ = timeline_service.add_event(&event);
+    }
+}
+
+fn archive(
+    app_handle: &AppHandle,
+    base_dir: &Path,
+    project_path: &Path,
+    exclude_ignored: bool,
+    drop_ignored_confirmed: bool,
+) -> Result<ArchivedProject, String> {
+    if !project_path.is_dir() {
+        return Err(format!("Project directory not found: {}", project_path.display()));
+    }
+    // The original is deleted afterwards, so ignored files such as `.env` would be gone for good
+    if exclude_ignored && !drop_ignored_confirmed {
+        return Err(
+            "Archiving without ignored files permanently deletes them, including local config such as .env; confirm to continue"
+                .to_string(),
+        );
+    }
+    let project_id = get_or_create_project_uuid(project_path.to_string_lossy().to_string())?;
+    let name = project_path.file_name().unwrap_or_default().to_string_lossy().to_string();
+    let category = project_path
+        .parent()
+        .and_then(|parent| parent.strip_prefix(base_dir).ok())
+        .map(|c| c.to_string_lossy().replace('\\', "/"))
+        .unwrap_or_default();
+
+    // Copies of a project share its id; never let one replace another's archive
+    let archive_path = archive_dir()?.join(format!("{}.{}", project_id, ARCHIVE_EXTENSION));
+    let service = app_handle.state::<ArchiveService>();
+    let already_archived = service
+        .get(&project_id)
+        .map_err(|e| format!("Failed to read archive record: {}", e))?
+        .is_some();
+    if already_archived || archive_path.exists() {
+        return Err(format!(
+            "A project with the same id ({}) is already archived; restore it first",
+            project_id
+        ));
+    }
+    pack(project_path, &archive_path, exclude_ignored)?;
+
+    let archived = ArchivedProject {
+        project_id: project_id.clone(),
+        name: name.clone(),
+        category,
+        original_path: project_path.to_string_lossy().to_string(),
+        archive_path: archive_path.to_string_lossy().to_string(),
+        size: fs::metadata(&archive_path).map(|m| m.len()).unwrap_or(0),
+        excluded_ignored: exclude_ignored,
+        archived_at: Utc::now().to_rfc3339(),
+    };
+    if let Err(e) = service.add(&archived) {
+        let _ = fs::remove_file(&archive_path);
+        return Err(format!("Failed to record archive: {}", e));
+    }
+
+    // The archive is safely recorded before anything is removed from the workspace
+    watcher::suspend_project_watch(app_handle, &project_path.to_string_lossy());
+    fs::remove_dir_all(project_path).map_err(|e| {
+        format!(
+            "Project was archived to {} but could not be removed: {}",
+            archive_path.display(),
+            e
+        )
+    })?;
+    relocate_path_state(app_handle, project_path, None)?;
+
+    record_event(
+        app_handle,
+        &project_id,
+        TimelineEventType::ProjectArchived {
+            archive: archived.archive_path.clone(),
+        },
+        format!("Project archived: {}", name),
+        "archived",
+    );
+    Ok(archived)
+}
+
+fn restore(
+    app_handle: &AppHandle,
+    project_id: &str,
+    base_dir: &Path,
+    category: Option<&str>,
+) -> Result<String, String> {
+    let service = app_handle.state::<ArchiveService>();
+    let archived = service
+        .get(project_id)
+        .map_err(|e| format!("Failed to read archive record: {}", e))?
+        .ok_or_else(|| format!("Project {} is not archived", project_id))?;
+
+    let target = match category {
+        Some(category) => category_dir(base_dir, category)?.join(&archived.name),
+        None => PathBuf::from(&archived.original_path),
+    };
+    if target.exists() {
+        return Err(format!("A project already exists at {}", target.display()));
+    }
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create category directory: {}", e))?;
+    }
+    unpack(Path::new(&archived.archive_path), &target)?;
+
+    service
+        .remove(project_id)
+        .map_err(|e| format!("Failed to update archive record: {}", e))?;
+    let _ = fs::remove_file(&archived.archive_path);
+
+    let restored_path = target.to_string_lossy().to_string();
+    record_event(
+        app_handle,
+        project_id,
+        TimelineEventType::ProjectRestored {
+            path: restored_path.clone(),
+        },
+        format!("Project restored: {}", archived.name),
+        "restored",
+    );
+    Ok(restored_path)
+}
+
+/// Pack a project into the archive area and remove it from the workspace.
+///
+/// Ignored files are kept unless `exclude_ignored` is set, which also needs `confirm_drop_ignored`
+/// since they can't be restored.
+#[tauri::command]
+pub async fn archive_project(
+    app_handle: AppHandle,
+    base_dir: String,
+    project_path: String,
+    exclude_ignored: Option<bool>,
+    confirm_drop_ignored: Option<bool>,
+) -> Result<ArchivedProject, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        archive(
+            &app_handle,
+            Path::new(&base_dir),
+            Path::new(&project_path),
+            exclude_ignored.unwrap_or(false),
+            confirm_drop_ignored.unwrap_or(false),
+        )
+    })
+    .await
+    .map_err(|e| format!("Archive task failed: {}", e))?
+}
+
+/// Unpack an archived project into its original location, or into `category` when given
+#[tauri::command]
+pub async fn restore_project(
+    app_handle: AppHandle,
+    project_id: String,
+    base_dir: String,
+    category: Option<String>,
+) -> Result<String, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        restore(&app_handle, &project_id, Path::new(&base_dir), category.as_deref())
+    })
+    .await
+    .map_err(|e| format!("Restore task failed: {}", e))?
+}
+
+#[tauri::command]
+pub fn list_archived_projects(state: tauri::State<'_, ArchiveService>) -> Result<Vec<ArchivedProject>, String> {
+    state.list().map_err(|e| format!("Failed to list archived projects: {}", e))
+}
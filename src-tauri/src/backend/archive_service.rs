@@ -0,0 +1,100 @@
+use rusqlite::{params, Connection, OptionalExtension, Result as SqlResult, Row};
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
+
+/// A project packed into the archive area; its timeline and tasks stay keyed by `project_id`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchivedProject {
+    pub project_id: String,
+    pub name: String,
+    /// Category the project was archived from, relative to the workspace
+    pub category: String,
+    pub original_path: String,
+    pub archive_path: String,
+    /// Compressed size in bytes
+    pub size: u64,
+    pub excluded_ignored: bool,
+    pub archived_at: String,
+}
+
+pub struct ArchiveService {
+    db: Arc<Mutex<Connection>>,
+}
+
+impl ArchiveService {
+    pub fn new(db_path: &str) -> Self {
+        let conn = Connection::open(db_path).expect("Failed to open archive DB");
+        conn.execute_batch(r#"
+            CREATE TABLE IF NOT EXISTS archived_projects (
+                project_id TEXT PRIMARY KEY,
+                name TEXT NOT NULL,
+                category TEXT NOT NULL,
+                original_path TEXT NOT NULL,
+                archive_path TEXT NOT NULL,
+                size INTEGER NOT NULL,
+                excluded_ignored INTEGER NOT NULL,
+                archived_at TEXT NOT NULL
+            );
+        "#).unwrap();
+        Self { db: Arc::new(Mutex::new(conn)) }
+    }
+
+    fn from_row(row: &Row) -> SqlResult<ArchivedProject> {
+        Ok(ArchivedProject {
+            project_id: row.get(0)?,
+            name: row.get(1)?,
+            category: row.get(2)?,
+            original_path: row.get(3)?,
+            archive_path: row.get(4)?,
+            size: row.get::<_, i64>(5)? as u64,
+            excluded_ignored: row.get(6)?,
+            archived_at: row.get(7)?,
+        })
+    }
+
+    pub fn add(&self, project: &ArchivedProject) -> SqlResult<()> {
+        let db = self.db.lock().unwrap();
+        db.execute(
+            "INSERT INTO archived_projects (project_id, name, category, original_path, archive_path, size, excluded_ignored, archived_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                project.project_id,
+                project.name,
+                project.category,
+                project.original_path,
+                project.archive_path,
+                project.size as i64,
+                project.excluded_ignored,
+                project.archived_at,
+            ]
+        )?;
+        Ok(())
+    }
+
+    pub fn get(&self, project_id: &str) -> SqlResult<Option<ArchivedProject>> {
+        let db = self.db.lock().unwrap();
+        db.query_row(
+            "SELECT project_id, name, category, original_path, archive_path, size, excluded_ignored, archived_at
+            FROM archived_projects WHERE project_id = ?1",
+            params![project_id],
+            Self::from_row,
+        )
+        .optional()
+    }
+
+    pub fn list(&self) -> SqlResult<Vec<ArchivedProject>> {
+        let db = self.db.lock().unwrap();
+        let mut stmt = db.prepare(
+            "SELECT project_id, name, category, original_path, archive_path, size, excluded_ignored, archived_at
+            FROM archived_projects ORDER BY archived_at DESC",
+        )?;
+        let rows = stmt.query_map([], Self::from_row)?;
+        rows.collect()
+    }
+
+    pub fn remove(&self, project_id: &str) -> SqlResult<()> {
+        let db = self.db.lock().unwrap();
+        db.execute("DELETE FROM archived_projects WHERE project_id = ?1", params![project_id])?;
+        Ok(())
+    }
+}
@@ -0,0 +1,396 @@
+use crate::backend::get_or_create_project_uuid;
+use crate::backend::git::{commit_info, open, GitCommitInfo, GitError};
+use crate::backend::timeline::{TimelineEvent, TimelineEventType};
+use crate::backend::timeline_service::TimelineService;
+use chrono::Utc;
+use git2::build::CheckoutBuilder;
+use git2::{Branch, BranchType, Repository, Signature, StatusOptions};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::Path;
+use tauri::{AppHandle, Emitter, Manager};
+use uuid::Uuid;
+
+/// Branches `delete_merged_branches` never removes
+const PROTECTED_BRANCHES: &[&str] = &["main", "master", "develop", "trunk"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BranchKind {
+    Local,
+    Remote,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BranchInfo {
+    /// `feature/x` for local branches, `origin/feature/x` for remote ones
+    pub name: String,
+    pub kind: BranchKind,
+    pub is_head: bool,
+    pub upstream: Option<String>,
+    /// Commits ahead of and behind the upstream; zero without one
+    pub ahead: usize,
+    pub behind: usize,
+    /// Whether the branch tip is already contained in HEAD
+    pub merged: bool,
+    pub last_commit: Option<GitCommitInfo>,
+}
+
+/// What `switch_branch` does when the working tree has uncommitted changes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DirtyTreeStrategy {
+    #[default]
+    Refuse,
+    Stash,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SwitchResult {
+    pub branch: String,
+    pub previous: Option<String>,
+    /// Id of the stash holding the changes that were in the way
+    pub stash: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MergedBranchCleanup {
+    pub deleted: Vec<String>,
+    /// One message per branch that couldn't be deleted; the others are still removed
+    pub errors: Vec<String>,
+}
+
+fn branch_info(repo: &Repository, branch: &Branch, kind: BranchKind) -> Result<BranchInfo, GitError> {
+    let name = branch.name()?.unwrap_or_default().to_string();
+    let tip = branch.get().peel_to_commit().ok();
+    let head = repo.head().ok().and_then(|h| h.target());
+
+    let mut info = BranchInfo {
+        name,
+        kind,
+        is_head: branch.is_head(),
+        upstream: None,
+        ahead: 0,
+        behind: 0,
+        merged: false,
+        last_commit: tip.as_ref().map(commit_info),
+    };
+    if let (Some(tip), Some(head)) = (tip.as_ref().map(|c| c.id()), head) {
+        info.merged = tip == head || repo.graph_descendant_of(head, tip)?;
+    }
+    if kind == BranchKind::Local {
+        if let Ok(upstream) = branch.upstream() {
+            info.upstream = upstream.name()?.map(str::to_string);
+            if let (Some(local), Some(remote)) = (branch.get().target(), upstream.get().target()) {
+                let (ahead, behind) = repo.graph_ahead_behind(local, remote)?;
+                info.ahead = ahead;
+                info.behind = behind;
+            }
+        }
+    }
+    Ok(info)
+}
+
+fn list(project_path: &Path) -> Result<Vec<BranchInfo>, GitError> {
+    let repo = open(project_path)?;
+    let mut branches = Vec::new();
+    for entry in repo.branches(None)? {
+        let (branch, branch_type) = entry?;
+        // `origin/HEAD` is an alias, not a branch
+        if branch_type == BranchType::Remote && branch.get().symbolic_target().is_some() {
+            continue;
+        }
+        let kind = match branch_type {
+            BranchType::Local => BranchKind::Local,
+            BranchType::Remote => BranchKind::Remote,
+        };
+        branches.push(branch_info(&repo, &branch, kind)?);
+    }
+    branches.sort_by(|a, b| (a.kind != BranchKind::Local, &a.name).cmp(&(b.kind != BranchKind::Local, &b.name)));
+    Ok(branches)
+}
+
+fn validate_branch_name(name: &str) -> Result<(), GitError> {
+    if name.starts_with('-') || !Branch::name_is_valid(name)? {
+        return Err(GitError::InvalidInput(format!("Invalid branch name: '{}'", name)));
+    }
+    Ok(())
+}
+
+fn current_branch(repo: &Repository) -> Option<String> {
+    let head = repo.head().ok()?;
+    if !head.is_branch() {
+        return None;
+    }
+    head.shorthand().map(str::to_string)
+}
+
+/// Uncommitted changes to tracked files; untracked files don't block a switch
+fn has_tracked_changes(repo: &Repository) -> Result<bool, GitError> {
+    let mut options = StatusOptions::new();
+    options.include_untracked(false).include_ignored(false);
+    Ok(!repo.statuses(Some(&mut options))?.is_empty())
+}
+
+fn stash_signature(repo: &Repository) -> Result<Signature<'static>, GitError> {
+    match repo.signature() {
+        Ok(signature) => Ok(signature.to_owned()),
+        Err(_) => Ok(Signature::now("Project Manager", "project-manager@localhost")?),
+    }
+}
+
+fn record_branch_event(
+    app_handle: &AppHandle,
+    project_path: &Path,
+    event_name: &str,
+    title: String,
+    metadata: HashMap<String, Value>,
+) {
+    let Ok(project_id) = get_or_create_project_uuid(project_path.to_string_lossy().to_string()) else {
+        return;
+    };
+    let event = TimelineEvent {
+        id: Uuid::new_v4(),
+        project_id: Uuid::parse_str(&project_id).unwrap_or_else(|_| Uuid::new_v4()),
+        timestamp: Utc::now(),
+        event_type: TimelineEventType::Custom {
+            event_name: event_name.to_string(),
+        },
+        title,
+        description: None,
+        metadata,
+        user_id: None,
+        tags: vec!["branch".to_string()],
+    };
+
+    let _ = app_handle.emit("timeline_event_added", &event);
+    if let Some(timeline_service) = app_handle.try_state::<TimelineService>() {
+        let _ = timeline_service.add_event(&event);
+    }
+}
+
+fn create(project_path: &Path, name: &str, from: Option<&str>) -> Result<BranchInfo, GitError> {
+    validate_branch_name(name)?;
+    let repo = open(project_path)?;
+    let start = from.map(str::trim).filter(|f| !f.is_empty()).unwrap_or("HEAD");
+    let commit = repo
+        .revparse_single(start)
+        .and_then(|object| object.peel_to_commit())
+        .map_err(|_| GitError::NotFound(format!("ref {}", start)))?;
+    if repo.find_branch(name, BranchType::Local).is_ok() {
+        return Err(GitError::InvalidInput(format!("Branch '{}' already exists", name)));
+    }
+    let branch = repo.branch(name, &commit, false)?;
+    branch_info(&repo, &branch, BranchKind::Local)
+}
+
+/// The `origin/<name>` style remote branch for `name`, as (full name, tip)
+fn find_remote_branch(repo: &Repository, name: &str) -> Result<(String, git2::Oid), GitError> {
+    for entry in repo.branches(Some(BranchType::Remote))? {
+        let (branch, _) = entry?;
+        let Some(full) = branch.name()?.map(str::to_string) else {
+            continue;
+        };
+        if full.split_once('/').map(|(_, short)| short == name).unwrap_or(false) {
+            return Ok((full, branch.get().peel_to_commit()?.id()));
+        }
+    }
+    Err(GitError::NotFound(format!("branch {}", name)))
+}
+
+/// Check out `name`, creating a local tracking branch when only `origin/<name>` exists
+fn switch(project_path: &Path, name: &str, strategy: DirtyTreeStrategy) -> Result<SwitchResult, GitError> {
+    let mut repo = open(project_path)?;
+    let previous = current_branch(&repo);
+    if previous.as_deref() == Some(name) {
+        return Ok(SwitchResult {
+            branch: name.to_string(),
+            previous,
+            stash: None,
+        });
+    }
+
+    let local = repo
+        .find_branch(name, BranchType::Local)
+        .ok()
+        .and_then(|branch| branch.get().target());
+    let remote = match local {
+        Some(_) => None,
+        None => Some(find_remote_branch(&repo, name)?),
+    };
+
+    let (target, upstream) = match (local, remote) {
+        (Some(target), _) => (target, None),
+        (None, Some((remote_name, target))) => (target, Some(remote_name)),
+        (None, None) => return Err(GitError::NotFound(format!("branch {}", name))),
+    };
+    // Resolved before anything is stashed, so a bad target never strands the user's changes
+    let commit_id = repo.find_commit(target)?.id();
+
+    let mut stash = None;
+    if has_tracked_changes(&repo)? {
+        match strategy {
+            DirtyTreeStrategy::Refuse => {
+                return Err(GitError::DirtyWorkingTree(
+                    "Commit or stash your changes before switching branches".to_string(),
+                ))
+            }
+            DirtyTreeStrategy::Stash => {
+                let signature = stash_signature(&repo)?;
+                let message = format!("Switching from {} to {}", previous.as_deref().unwrap_or("HEAD"), name);
+                stash = Some(repo.stash_save(&signature, &message, None)?.to_string());
+            }
+        }
+    }
+
+    if let Err(e) = check_out(&repo, name, commit_id, upstream.as_deref()) {
+        let Some(stash) = stash else {
+            return Err(e);
+        };
+        // Put the changes back where they were; if that fails too, say where they are
+        return Err(match repo.stash_pop(0, None) {
+            Ok(()) => e,
+            Err(_) => GitError::Git(format!("{}; your changes are saved in stash {}", e, stash)),
+        });
+    }
+    Ok(SwitchResult {
+        branch: name.to_string(),
+        previous,
+        stash,
+    })
+}
+
+/// Point HEAD and the working tree at `name`, first creating it at `commit_id` to track `upstream` when given
+fn check_out(repo: &Repository, name: &str, commit_id: git2::Oid, upstream: Option<&str>) -> Result<(), GitError> {
+    let commit = repo.find_commit(commit_id)?;
+    if let Some(upstream) = upstream {
+        let mut branch = repo.branch(name, &commit, false)?;
+        if let Err(e) = branch.set_upstream(Some(upstream)) {
+            let _ = branch.delete();
+            return Err(e.into());
+        }
+    }
+    let result = repo
+        .checkout_tree(commit.as_object(), Some(CheckoutBuilder::new().safe()))
+        .and_then(|_| repo.set_head(&format!("refs/heads/{}", name)));
+    if let Err(e) = result {
+        // Don't leave behind a tracking branch nobody asked for
+        if upstream.is_some() {
+            if let Ok(mut branch) = repo.find_branch(name, BranchType::Local) {
+                let _ = branch.delete();
+            }
+        }
+        return Err(e.into());
+    }
+    Ok(())
+}
+
+/// Delete local branches already merged into HEAD; `only` limits the candidates
+fn delete_merged(project_path: &Path, only: Option<&[String]>) -> Result<MergedBranchCleanup, GitError> {
+    let repo = open(project_path)?;
+    let head = repo.head()?.peel_to_commit()?.id();
+    let current = current_branch(&repo);
+
+    let mut cleanup = MergedBranchCleanup {
+        deleted: Vec::new(),
+        errors: Vec::new(),
+    };
+    for entry in repo.branches(Some(BranchType::Local))? {
+        let (mut branch, _) = entry?;
+        let Some(name) = branch.name()?.map(str::to_string) else {
+            continue;
+        };
+        if current.as_deref() == Some(name.as_str())
+            || PROTECTED_BRANCHES.contains(&name.as_str())
+            || only.map(|names| !names.contains(&name)).unwrap_or(false)
+        {
+            continue;
+        }
+        let Some(tip) = branch.get().target() else {
+            continue;
+        };
+        let merged = tip == head || repo.graph_descendant_of(head, tip).unwrap_or(false);
+        if !merged {
+            continue;
+        }
+        match branch.delete() {
+            Ok(()) => cleanup.deleted.push(name),
+            Err(e) => cleanup.errors.push(format!("Failed to delete {}: {}", name, e.message())),
+        }
+    }
+    Ok(cleanup)
+}
+
+#[tauri::command]
+pub fn list_branches(project_path: String) -> Result<Vec<BranchInfo>, GitError> {
+    list(Path::new(&project_path))
+}
+
+/// Create a branch at `from` (a branch, tag or commit; HEAD by default)
+#[tauri::command]
+pub fn create_branch(
+    app_handle: AppHandle,
+    project_path: String,
+    name: String,
+    from: Option<String>,
+) -> Result<BranchInfo, GitError> {
+    let path = Path::new(&project_path);
+    let name = name.trim();
+    let branch = create(path, name, from.as_deref())?;
+
+    let mut metadata = HashMap::new();
+    metadata.insert("branch".to_string(), Value::String(name.to_string()));
+    if let Some(commit) = &branch.last_commit {
+        metadata.insert("commit".to_string(), Value::String(commit.hash.clone()));
+    }
+    record_branch_event(
+        &app_handle,
+        path,
+        "branch_created",
+        format!("Branch created: {}", name),
+        metadata,
+    );
+    Ok(branch)
+}
+
+/// Check out a branch; with uncommitted changes this refuses unless `dirty` is `stash`
+#[tauri::command]
+pub fn switch_branch(
+    app_handle: AppHandle,
+    project_path: String,
+    name: String,
+    dirty: Option<DirtyTreeStrategy>,
+) -> Result<SwitchResult, GitError> {
+    let path = Path::new(&project_path);
+    let result = switch(path, name.trim(), dirty.unwrap_or_default())?;
+    if result.previous.as_deref() == Some(result.branch.as_str()) {
+        return Ok(result);
+    }
+
+    let mut metadata = HashMap::new();
+    metadata.insert("branch".to_string(), Value::String(result.branch.clone()));
+    if let Some(previous) = &result.previous {
+        metadata.insert("previous".to_string(), Value::String(previous.clone()));
+    }
+    if let Some(stash) = &result.stash {
+        metadata.insert("stash".to_string(), Value::String(stash.clone()));
+    }
+    record_branch_event(
+        &app_handle,
+        path,
+        "branch_switched",
+        format!("Switched to branch {}", result.branch),
+        metadata,
+    );
+    Ok(result)
+}
+
+/// Delete local branches merged into HEAD, except the current and default branches
+#[tauri::command]
+pub fn delete_merged_branches(
+    project_path: String,
+    branches: Option<Vec<String>>,
+) -> Result<MergedBranchCleanup, GitError> {
+    delete_merged(Path::new(&project_path), branches.as_deref())
+}
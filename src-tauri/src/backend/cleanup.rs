@@ -0,0 +1,297 @@
+use crate::backend::get_or_create_project_uuid;
+use crate::backend::git::contains_tracked_files;
+use crate::backend::ignore_rules::total_size;
+use crate::backend::project_scan::{collect_scan_targets, ScanTarget};
+use crate::backend::timeline::{TimelineEvent, TimelineEventType};
+use crate::backend::timeline_service::TimelineService;
+use crate::workspace;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use tauri::{AppHandle, Emitter, Manager};
+use uuid::Uuid;
+
+/// A reclaimable directory name and the sibling files, any of which must exist for it to count.
+///
+/// Generic names like `dist` or `build` are only treated as output next to a manifest that produces them.
+struct ArtifactRule {
+    name: &'static str,
+    kind: &'static str,
+    requires: &'static [&'static str],
+    /// Skip the directory when git tracks files in it, e.g. electron-builder resources under `build/`
+    untracked_only: bool,
+}
+
+const ARTIFACT_RULES: &[ArtifactRule] = &[
+    ArtifactRule { name: "target", kind: "rust", requires: &["Cargo.toml"], untracked_only: false },
+    ArtifactRule { name: "target", kind: "maven", requires: &["pom.xml"], untracked_only: false },
+    ArtifactRule { name: "node_modules", kind: "node", requires: &[], untracked_only: false },
+    ArtifactRule { name: "dist", kind: "dist", requires: &["package.json", "pyproject.toml", "setup.py"], untracked_only: true },
+    ArtifactRule { name: "build", kind: "build", requires: &["package.json", "build.gradle", "build.gradle.kts", "setup.py", "pubspec.yaml"], untracked_only: true },
+    ArtifactRule { name: ".next", kind: "next", requires: &[], untracked_only: false },
+    ArtifactRule { name: ".nuxt", kind: "nuxt", requires: &[], untracked_only: false },
+    ArtifactRule { name: ".svelte-kit", kind: "svelte", requires: &[], untracked_only: false },
+    ArtifactRule { name: ".turbo", kind: "turbo", requires: &[], untracked_only: false },
+    ArtifactRule { name: ".parcel-cache", kind: "parcel", requires: &[], untracked_only: false },
+    ArtifactRule { name: ".venv", kind: "python", requires: &[], untracked_only: false },
+    ArtifactRule { name: "venv", kind: "python", requires: &["pyvenv.cfg"], untracked_only: false },
+    ArtifactRule { name: "__pycache__", kind: "python", requires: &[], untracked_only: false },
+    ArtifactRule { name: ".pytest_cache", kind: "python", requires: &[], untracked_only: false },
+    ArtifactRule { name: ".mypy_cache", kind: "python", requires: &[], untracked_only: false },
+    ArtifactRule { name: ".ruff_cache", kind: "python", requires: &[], untracked_only: false },
+    ArtifactRule { name: ".gradle", kind: "gradle", requires: &[], untracked_only: false },
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArtifactEntry {
+    pub path: String,
+    /// Path relative to the project root
+    pub relative_path: String,
+    pub kind: String,
+    pub size: u64,
+    pub files_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectArtifacts {
+    pub project_path: String,
+    pub project_name: String,
+    pub entries: Vec<ArtifactEntry>,
+    pub total_size: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CleanupReport {
+    pub projects: Vec<ProjectArtifacts>,
+    pub total_size: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CleanupResult {
+    pub removed: Vec<ArtifactEntry>,
+    pub freed: u64,
+    pub errors: Vec<String>,
+}
+
+/// The rule `dir` matches, if it is build output
+fn matching_rule(dir: &Path) -> Option<&'static ArtifactRule> {
+    let name = dir.file_name()?.to_str()?;
+    let parent = dir.parent()?;
+    ARTIFACT_RULES.iter().find(|rule| {
+        rule.name == name
+            && (rule.requires.is_empty()
+                || rule
+                    .requires
+                    .iter()
+                    .any(|file| parent.join(file).exists() || dir.join(file).exists()))
+            && !(rule.untracked_only && contains_tracked_files(dir))
+    })
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+/// Collect build output under `dir` without descending into it or into `.git`
+fn find_artifacts(root: &Path, dir: &Path, entries: &mut Vec<ArtifactEntry>) {
+    let Ok(children) = fs::read_dir(dir) else {
+        return;
+    };
+    let mut children = children
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().map(|t| t.is_dir()).unwrap_or(false))
+        .map(|e| e.path())
+        .filter(|p| p.file_name().map(|n| n != ".git").unwrap_or(false))
+        .collect::<Vec<_>>();
+    children.sort();
+
+    for child in children {
+        match matching_rule(&child) {
+            Some(rule) => {
+                let (size, files_count) = total_size(&child);
+                entries.push(ArtifactEntry {
+                    path: child.to_string_lossy().to_string(),
+                    relative_path: child
+                        .strip_prefix(root)
+                        .unwrap_or(&child)
+                        .to_string_lossy()
+                        .replace('\\', "/"),
+                    kind: rule.kind.to_string(),
+                    size,
+                    files_count,
+                });
+            }
+            None => find_artifacts(root, &child, entries),
+        }
+    }
+}
+
+fn project_artifacts(project_path: &Path) -> ProjectArtifacts {
+    let mut entries = Vec::new();
+    find_artifacts(project_path, project_path, &mut entries);
+    entries.sort_by(|a, b| b.size.cmp(&a.size));
+    ProjectArtifacts {
+        project_path: project_path.to_string_lossy().to_string(),
+        project_name: project_path.file_name().unwrap_or_default().to_string_lossy().to_string(),
+        total_size: entries.iter().map(|e| e.size).sum(),
+        entries,
+    }
+}
+
+fn workspace_projects(app_handle: &AppHandle, base_dir: &Path) -> Result<Vec<ScanTarget>, String> {
+    let (_, targets) = collect_scan_targets(base_dir, workspace::discovery_depth(app_handle))?;
+    Ok(targets)
+}
+
+fn report(app_handle: &AppHandle, base_dir: &Path, project_path: Option<&Path>) -> Result<CleanupReport, String> {
+    let mut projects = match project_path {
+        Some(path) => {
+            if !path.is_dir() {
+                return Err(format!("Project directory not found: {}", path.display()));
+            }
+            vec![project_artifacts(path)]
+        }
+        None => workspace_projects(app_handle, base_dir)?
+            .iter()
+            .map(|target| project_artifacts(&target.path))
+            .filter(|project| !project.entries.is_empty())
+            .collect(),
+    };
+    projects.sort_by(|a, b| b.total_size.cmp(&a.total_size));
+    Ok(CleanupReport {
+        total_size: projects.iter().map(|p| p.total_size).sum(),
+        projects,
+    })
+}
+
+fn record_cleanup(app_handle: &AppHandle, project_path: &Path, removed: &[ArtifactEntry]) {
+    let Ok(project_id) = get_or_create_project_uuid(project_path.to_string_lossy().to_string()) else {
+        return;
+    };
+    let freed: u64 = removed.iter().map(|e| e.size).sum();
+    let mut metadata = HashMap::new();
+    metadata.insert("freed_bytes".to_string(), Value::from(freed));
+    metadata.insert(
+        "removed".to_string(),
+        Value::from(removed.iter().map(|e| e.relative_path.clone()).collect::<Vec<_>>()),
+    );
+
+    let event = TimelineEvent {
+        id: Uuid::new_v4(),
+        project_id: Uuid::parse_str(&project_id).unwrap_or_else(|_| Uuid::new_v4()),
+        timestamp: Utc::now(),
+        event_type: TimelineEventType::Custom {
+            event_name: "artifacts_cleaned".to_string(),
+        },
+        title: format!("Cleaned build output: {} freed", format_bytes(freed)),
+        description: Some(format!(
+            "Removed {}",
+            removed
+                .iter()
+                .map(|e| e.relative_path.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+        metadata,
+        user_id: None,
+        tags: vec!["cleanup".to_string()],
+    };
+
+    let _ = app_handle.emit("timeline_event_added", &event);
+    if let Some(timeline_service) = app_handle.try_state::<TimelineService>() {
+        let _ = timeline_service.add_event(&event);
+    }
+}
+
+fn clean(app_handle: &AppHandle, base_dir: &Path, paths: &[String]) -> Result<CleanupResult, String> {
+    let projects = workspace_projects(app_handle, base_dir)?;
+
+    let mut result = CleanupResult {
+        removed: Vec::new(),
+        freed: 0,
+        errors: Vec::new(),
+    };
+    let mut removed_by_project: BTreeMap<PathBuf, Vec<ArtifactEntry>> = BTreeMap::new();
+    for path in paths {
+        let dir = PathBuf::from(path);
+        // Symlinks and `..` could point the deletion outside the project
+        let is_real_dir = fs::symlink_metadata(&dir).map(|m| m.is_dir()).unwrap_or(false)
+            && !dir
+                .components()
+                .any(|c| matches!(c, Component::ParentDir | Component::CurDir));
+        let rule = matching_rule(&dir);
+        let project = projects
+            .iter()
+            .filter(|target| dir.starts_with(&target.path) && dir != target.path)
+            .max_by_key(|target| target.path.components().count());
+        let (Some(rule), Some(project), true) = (rule, project, is_real_dir) else {
+            result.errors.push(format!("{} is not build output inside a workspace project", path));
+            continue;
+        };
+
+        let (size, files_count) = total_size(&dir);
+        if let Err(e) = fs::remove_dir_all(&dir) {
+            result.errors.push(format!("Failed to remove {}: {}", path, e));
+            continue;
+        }
+        let entry = ArtifactEntry {
+            path: path.clone(),
+            relative_path: dir
+                .strip_prefix(&project.path)
+                .unwrap_or(&dir)
+                .to_string_lossy()
+                .replace('\\', "/"),
+            kind: rule.kind.to_string(),
+            size,
+            files_count,
+        };
+        result.freed += size;
+        result.removed.push(entry.clone());
+        removed_by_project.entry(project.path.clone()).or_default().push(entry);
+    }
+
+    for (project_path, removed) in &removed_by_project {
+        record_cleanup(app_handle, project_path, removed);
+    }
+    Ok(result)
+}
+
+/// Dry run: reclaimable build output per project, for one project or the whole workspace
+#[tauri::command]
+pub async fn find_build_artifacts(
+    app_handle: AppHandle,
+    base_dir: String,
+    project_path: Option<String>,
+) -> Result<CleanupReport, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        report(&app_handle, Path::new(&base_dir), project_path.as_deref().map(Path::new))
+    })
+    .await
+    .map_err(|e| format!("Cleanup scan failed: {}", e))?
+}
+
+/// Delete the selected build output directories, as listed by `find_build_artifacts`
+#[tauri::command]
+pub async fn clean_build_artifacts(
+    app_handle: AppHandle,
+    base_dir: String,
+    paths: Vec<String>,
+) -> Result<CleanupResult, String> {
+    tauri::async_runtime::spawn_blocking(move || clean(&app_handle, Path::new(&base_dir), &paths))
+        .await
+        .map_err(|e| format!("Cleanup task failed: {}", e))?
+}
@@ -0,0 +1,282 @@
+use crate::backend::get_or_create_project_uuid;
+use crate::backend::git::git_summary;
+use crate::backend::git_history::{import_history, HistoryRange};
+use crate::backend::project_detect;
+use crate::backend::project_meta::METADATA_FILE;
+use crate::backend::relocate::category_dir;
+use crate::backend::timeline::{TimelineEvent, TimelineEventType};
+use crate::backend::timeline_service::TimelineService;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{self, OpenOptions};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use tauri::{AppHandle, Emitter, Manager};
+use uuid::Uuid;
+
+/// Emitted as `project_clone_progress` while a clone runs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CloneProgress {
+    pub destination: String,
+    /// `Receiving objects`, `Resolving deltas`, ... or `history` while commits are imported
+    pub stage: String,
+    pub percent: Option<u8>,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClonedProject {
+    pub project_id: String,
+    pub project_path: String,
+    pub project_type: String,
+    pub branch: Option<String>,
+    pub imported_commits: usize,
+}
+
+/// Directory name for a clone: the last path segment of the source without `.git`
+fn repository_name(source: &str) -> Option<String> {
+    let trimmed = source.trim_end_matches(['/', '\\']);
+    let last = trimmed.rsplit(['/', '\\', ':']).next()?;
+    let name = last.strip_suffix(".git").unwrap_or(last);
+    if name.is_empty() || name == "." || name == ".." {
+        None
+    } else {
+        Some(name.to_string())
+    }
+}
+
+/// Local paths need a `file://` URL for `--depth` to take effect
+fn clone_source(source: &str, shallow: bool) -> String {
+    let local = Path::new(source);
+    if shallow && local.exists() {
+        let absolute = local.canonicalize().unwrap_or_else(|_| local.to_path_buf());
+        return format!("file://{}", absolute.to_string_lossy().replace('\\', "/"));
+    }
+    source.to_string()
+}
+
+/// Parse a `git clone --progress` line like `Receiving objects:  45% (9/20)`
+fn parse_progress(line: &str) -> Option<(String, Option<u8>)> {
+    let line = line.strip_prefix("remote: ").unwrap_or(line);
+    let (stage, rest) = line.split_once(':')?;
+    let percent = rest
+        .split_whitespace()
+        .next()
+        .and_then(|word| word.strip_suffix('%'))
+        .and_then(|n| n.parse().ok());
+    Some((stage.trim().to_string(), percent))
+}
+
+fn emit_progress(app_handle: &AppHandle, destination: &Path, stage: &str, percent: Option<u8>, message: &str) {
+    let _ = app_handle.emit(
+        "project_clone_progress",
+        &CloneProgress {
+            destination: destination.to_string_lossy().to_string(),
+            stage: stage.to_string(),
+            percent,
+            message: message.to_string(),
+        },
+    );
+}
+
+/// Run `git clone`, forwarding its progress output as events
+fn run_clone(
+    app_handle: &AppHandle,
+    source: &str,
+    target: &Path,
+    branch: Option<&str>,
+    depth: Option<u32>,
+) -> Result<(), String> {
+    let mut command = Command::new("git");
+    command.args(["clone", "--progress"]);
+    if let Some(branch) = branch {
+        command.args(["--branch", branch]);
+    }
+    if let Some(depth) = depth {
+        command.args(["--depth", &depth.to_string()]);
+    }
+    command
+        .arg("--")
+        .arg(clone_source(source, depth.is_some()))
+        .arg(target)
+        .env("GIT_TERMINAL_PROMPT", "0")
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped());
+
+    let mut child = command.spawn().map_err(|e| format!("Failed to run git clone: {}", e))?;
+    let mut stderr = child.stderr.take().ok_or("Failed to read git clone output")?;
+
+    // Progress lines are terminated by `\r`, everything else by `\n`
+    let mut messages = Vec::new();
+    let mut line = Vec::new();
+    let mut last = None;
+    let mut buf = [0u8; 4096];
+    loop {
+        let n = stderr.read(&mut buf).map_err(|e| format!("Failed to read git clone output: {}", e))?;
+        if n == 0 {
+            break;
+        }
+        for &byte in &buf[..n] {
+            if byte != b'\r' && byte != b'\n' {
+                line.push(byte);
+                continue;
+            }
+            let text = String::from_utf8_lossy(&line).trim().to_string();
+            line.clear();
+            if text.is_empty() {
+                continue;
+            }
+            match parse_progress(&text) {
+                Some((stage, percent)) if percent.is_some() => {
+                    if last.as_ref() != Some(&(stage.clone(), percent)) {
+                        emit_progress(app_handle, target, &stage, percent, &text);
+                        last = Some((stage, percent));
+                    }
+                }
+                _ => {
+                    emit_progress(app_handle, target, "clone", None, &text);
+                    messages.push(text);
+                }
+            }
+        }
+    }
+
+    let status = child.wait().map_err(|e| format!("Failed to run git clone: {}", e))?;
+    if !status.success() {
+        let detail = messages
+            .iter()
+            .filter(|m| !m.starts_with("Cloning into"))
+            .cloned()
+            .collect::<Vec<_>>()
+            .join("\n");
+        return Err(format!("git clone failed: {}", detail));
+    }
+    Ok(())
+}
+
+/// Keep `.project.toml` out of the clone's status without touching its tracked `.gitignore`
+fn exclude_metadata_file(target: &Path) {
+    let info = target.join(".git").join("info");
+    if fs::create_dir_all(&info).is_err() {
+        return;
+    }
+    let exclude = info.join("exclude");
+    let existing = fs::read_to_string(&exclude).unwrap_or_default();
+    if existing.lines().any(|l| l.trim() == METADATA_FILE) {
+        return;
+    }
+    if let Ok(mut file) = OpenOptions::new().create(true).append(true).open(&exclude) {
+        let separator = if existing.is_empty() || existing.ends_with('\n') { "" } else { "\n" };
+        let _ = writeln!(file, "{}{}", separator, METADATA_FILE);
+    }
+}
+
+fn record_clone(app_handle: &AppHandle, project_id: Uuid, name: &str, source: &str, project_type: &str) {
+    let event = TimelineEvent {
+        id: Uuid::new_v4(),
+        project_id,
+        timestamp: Utc::now(),
+        event_type: TimelineEventType::ProjectCreated,
+        title: format!("Project cloned: {}", name),
+        description: Some(format!("Cloned from {} as a {} project.", source, project_type)),
+        metadata: HashMap::new(),
+        user_id: None,
+        tags: vec!["cloned".to_string(), project_type.to_string()],
+    };
+
+    let _ = app_handle.emit("timeline_event_added", &event);
+    if let Some(timeline_service) = app_handle.try_state::<TimelineService>() {
+        let _ = timeline_service.add_event(&event);
+    }
+}
+
+fn clone(
+    app_handle: &AppHandle,
+    base_dir: &Path,
+    category: &str,
+    source: &str,
+    name: Option<&str>,
+    branch: Option<&str>,
+    depth: Option<u32>,
+) -> Result<ClonedProject, String> {
+    let source = source.trim();
+    if source.is_empty() {
+        return Err("A repository URL or path is required".to_string());
+    }
+    // git would read these as options, e.g. `--upload-pack=<command>`
+    if source.starts_with('-') {
+        return Err(format!("Invalid repository URL or path: '{}'", source));
+    }
+    if let Some(branch) = branch.filter(|b| b.starts_with('-')) {
+        return Err(format!("Invalid branch: '{}'", branch));
+    }
+    let name = match name.map(str::trim).filter(|n| !n.is_empty()) {
+        Some(name) => name.to_string(),
+        None => repository_name(source)
+            .ok_or_else(|| format!("Can't derive a project name from {}", source))?,
+    };
+    if name.contains(['/', '\\']) || name == "." || name == ".." {
+        return Err(format!("Invalid project name: '{}'", name));
+    }
+    let target: PathBuf = category_dir(base_dir, category)?.join(&name);
+    if target.exists() {
+        return Err(format!("A project already exists at {}", target.display()));
+    }
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create category directory: {}", e))?;
+    }
+
+    if let Err(e) = run_clone(app_handle, source, &target, branch, depth) {
+        let _ = fs::remove_dir_all(&target);
+        return Err(e);
+    }
+
+    let project_id = get_or_create_project_uuid(target.to_string_lossy().to_string())?;
+    let uuid = Uuid::parse_str(&project_id).map_err(|e| e.to_string())?;
+    exclude_metadata_file(&target);
+    let project_type = project_detect::registry().detect(&target).primary;
+    record_clone(app_handle, uuid, &name, source, &project_type);
+
+    emit_progress(app_handle, &target, "history", None, "Importing commit history");
+    let imported_commits = import_history(app_handle, uuid, &target, &HistoryRange::default())?.imported;
+
+    let checked_out = git_summary(&target).branch;
+    Ok(ClonedProject {
+        project_id,
+        project_path: target.to_string_lossy().to_string(),
+        project_type,
+        branch: checked_out,
+        imported_commits,
+    })
+}
+
+/// Clone a repository URL or local path (bare repositories included) into a workspace category.
+///
+/// Emits `project_clone_progress` while cloning and importing history.
+#[tauri::command]
+pub async fn clone_project(
+    app_handle: AppHandle,
+    base_dir: String,
+    category: String,
+    source: String,
+    name: Option<String>,
+    branch: Option<String>,
+    depth: Option<u32>,
+) -> Result<ClonedProject, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        clone(
+            &app_handle,
+            Path::new(&base_dir),
+            &category,
+            &source,
+            name.as_deref(),
+            branch.as_deref(),
+            depth,
+        )
+    })
+    .await
+    .map_err(|e| format!("Clone task failed: {}", e))?
+}
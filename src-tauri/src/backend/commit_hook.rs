@@ -0,0 +1,237 @@
+use crate::backend::get_or_create_project_uuid;
+use crate::backend::git::open;
+use crate::backend::git_history::{commit_event, read_commit};
+use crate::backend::timeline_service::TimelineService;
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use notify_debouncer_full::{new_debouncer, DebounceEventResult, Debouncer, FileIdMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+use uuid::Uuid;
+
+const HOOK_NAME: &str = "post-commit";
+/// The project's own post-commit hook is moved here and run before ours
+const CHAINED_HOOK_NAME: &str = "post-commit.project-manager-chained";
+const HOOK_MARKER: &str = "# project-manager post-commit hook";
+const SPOOL_EXTENSION: &str = "commit";
+const SPOOL_DEBOUNCE: Duration = Duration::from_millis(500);
+
+const HOOK_TEMPLATE: &str = r#"#!/bin/sh
+{{marker}}
+# Queues each commit for the Project Manager timeline. The project's previous
+# post-commit hook, if any, was kept as {{chained}} and runs first.
+hook_dir=$(dirname "$0")
+status=0
+if [ -x "$hook_dir/{{chained}}" ]; then
+    "$hook_dir/{{chained}}" "$@"
+    status=$?
+fi
+spool='{{spool}}'
+hash=$(git rev-parse HEAD 2>/dev/null) || exit $status
+root=$(git rev-parse --show-toplevel 2>/dev/null) || exit $status
+name="$(date +%s)-$$-$hash"
+mkdir -p "$spool" 2>/dev/null &&
+    printf '%s\n%s\n' "$hash" "$root" > "$spool/.$name.tmp" &&
+    mv "$spool/.$name.tmp" "$spool/$name.{{extension}}"
+exit $status
+"#;
+
+/// Keeps the spool directory watched while the app runs
+#[derive(Default)]
+pub struct CommitSpoolService {
+    watcher: Mutex<Option<Debouncer<RecommendedWatcher, FileIdMap>>>,
+    /// Held while draining so the startup drain and the watcher don't record a commit twice
+    draining: Mutex<()>,
+}
+
+/// Where hooks leave commits for the app to pick up
+pub fn spool_dir() -> Result<PathBuf, String> {
+    let mut path = dirs::data_dir().ok_or("Could not find data directory")?;
+    path.push("project-manager");
+    path.push("commit-spool");
+    fs::create_dir_all(&path).map_err(|e| format!("Failed to create commit spool dir: {}", e))?;
+    Ok(path)
+}
+
+/// The hooks directory git actually uses, honoring `core.hooksPath`
+fn hooks_dir(project_path: &Path) -> Result<PathBuf, String> {
+    let repo = open(project_path)?;
+    let configured = repo
+        .config()
+        .and_then(|config| config.get_path("core.hooksPath"))
+        .ok();
+    Ok(match configured {
+        // Relative paths are resolved against the working tree, where hooks run
+        Some(hooks) => project_path.join(hooks),
+        None => repo.commondir().join("hooks"),
+    })
+}
+
+fn is_our_hook(hook: &Path) -> bool {
+    fs::read_to_string(hook)
+        .map(|contents| contents.contains(HOOK_MARKER))
+        .unwrap_or(false)
+}
+
+fn hook_script(spool: &Path) -> String {
+    // Single-quoted for sh, so only `'` itself needs escaping
+    let spool = spool.to_string_lossy().replace('\\', "/").replace('\'', r"'\''");
+    HOOK_TEMPLATE
+        .replace("{{marker}}", HOOK_MARKER)
+        .replace("{{chained}}", CHAINED_HOOK_NAME)
+        .replace("{{spool}}", &spool)
+        .replace("{{extension}}", SPOOL_EXTENSION)
+}
+
+fn make_executable(path: &Path) -> Result<(), String> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(path, fs::Permissions::from_mode(0o755))
+            .map_err(|e| format!("Failed to make {} executable: {}", path.display(), e))?;
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+    Ok(())
+}
+
+fn install(project_path: &Path) -> Result<(), String> {
+    let hooks = hooks_dir(project_path)?;
+    fs::create_dir_all(&hooks).map_err(|e| format!("Failed to create hooks dir: {}", e))?;
+    let hook = hooks.join(HOOK_NAME);
+    let chained = hooks.join(CHAINED_HOOK_NAME);
+
+    if hook.exists() && !is_our_hook(&hook) {
+        if chained.exists() {
+            return Err(format!(
+                "Both {} and {} exist; remove one before installing",
+                hook.display(),
+                chained.display()
+            ));
+        }
+        fs::rename(&hook, &chained).map_err(|e| format!("Failed to keep existing hook: {}", e))?;
+    }
+    // Reinstalling an existing hook refreshes the script, e.g. after the data dir moved
+    fs::write(&hook, hook_script(&spool_dir()?)).map_err(|e| format!("Failed to write hook: {}", e))?;
+    make_executable(&hook)
+}
+
+fn uninstall(project_path: &Path) -> Result<(), String> {
+    let hooks = hooks_dir(project_path)?;
+    let hook = hooks.join(HOOK_NAME);
+    if !is_our_hook(&hook) {
+        return Err(format!("No project manager hook is installed in {}", hooks.display()));
+    }
+    fs::remove_file(&hook).map_err(|e| format!("Failed to remove hook: {}", e))?;
+    let chained = hooks.join(CHAINED_HOOK_NAME);
+    if chained.exists() {
+        fs::rename(&chained, &hook).map_err(|e| format!("Failed to restore previous hook: {}", e))?;
+    }
+    Ok(())
+}
+
+/// Record one spooled commit; `Ok(false)` when it was already on the timeline or no longer exists
+fn record_spooled_commit(app_handle: &AppHandle, hash: &str, project_path: &Path) -> Result<bool, String> {
+    let project_id = Uuid::parse_str(&get_or_create_project_uuid(project_path.to_string_lossy().to_string())?)
+        .map_err(|e| e.to_string())?;
+    let timeline_service = app_handle
+        .try_state::<TimelineService>()
+        .ok_or("Timeline service is not available")?;
+    let known = timeline_service
+        .commit_hashes(project_id)
+        .map_err(|e| format!("Failed to read timeline: {}", e))?;
+    if known.contains(hash) {
+        return Ok(false);
+    }
+
+    // Commits amended away or garbage-collected since they were queued are dropped
+    let Ok(commit) = read_commit(project_path, hash) else {
+        return Ok(false);
+    };
+    let mut event = commit_event(project_id, &commit);
+    event.tags = vec!["commit".to_string(), "hook".to_string()];
+    timeline_service
+        .add_event(&event)
+        .map_err(|e| format!("Failed to record commit: {}", e))?;
+    let _ = app_handle.emit("timeline_event_added", &event);
+    Ok(true)
+}
+
+/// Turn every queued commit into a timeline event; returns how many were recorded.
+///
+/// Spool files are removed once handled, including ones for projects that no longer exist.
+pub fn drain_commit_spool(app_handle: &AppHandle) -> Result<usize, String> {
+    let service = app_handle.state::<CommitSpoolService>();
+    let _draining = service.draining.lock().map_err(|_| "Failed to lock commit spool".to_string())?;
+    let spool = spool_dir()?;
+    let mut files = fs::read_dir(&spool)
+        .map_err(|e| format!("Failed to read commit spool: {}", e))?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().map(|ext| ext == SPOOL_EXTENSION).unwrap_or(false))
+        .collect::<Vec<_>>();
+    // Names start with the commit time, so this records commits in order
+    files.sort();
+
+    let mut recorded = 0;
+    for file in files {
+        let Ok(contents) = fs::read_to_string(&file) else {
+            continue;
+        };
+        let mut lines = contents.lines();
+        if let (Some(hash), Some(root)) = (lines.next(), lines.next()) {
+            let project_path = Path::new(root.trim());
+            if project_path.is_dir() {
+                match record_spooled_commit(app_handle, hash.trim(), project_path) {
+                    Ok(true) => recorded += 1,
+                    Ok(false) => {}
+                    // Keep the file so the commit is retried on the next drain
+                    Err(_) => continue,
+                }
+            }
+        }
+        let _ = fs::remove_file(&file);
+    }
+    Ok(recorded)
+}
+
+/// Drain commits made while the app was closed, then keep watching for new ones
+pub fn start_commit_spool(app_handle: &AppHandle) -> Result<(), String> {
+    let _ = drain_commit_spool(app_handle);
+
+    let spool = spool_dir()?;
+    let handle = app_handle.clone();
+    let mut debouncer = new_debouncer(SPOOL_DEBOUNCE, None, move |result: DebounceEventResult| {
+        if result.is_ok() {
+            let _ = drain_commit_spool(&handle);
+        }
+    })
+    .map_err(|e| format!("Failed to create watcher: {}", e))?;
+    debouncer
+        .watcher()
+        .watch(&spool, RecursiveMode::NonRecursive)
+        .map_err(|e| format!("Failed to watch commit spool: {}", e))?;
+
+    let service = app_handle.state::<CommitSpoolService>();
+    *service.watcher.lock().map_err(|_| "Failed to lock spool watcher".to_string())? = Some(debouncer);
+    Ok(())
+}
+
+/// Install a post-commit hook that records every commit on the project's timeline
+#[tauri::command]
+pub fn install_commit_hook(project_path: String) -> Result<(), String> {
+    install(Path::new(&project_path))
+}
+
+/// Remove the hook and put back any post-commit hook it had chained
+#[tauri::command]
+pub fn uninstall_commit_hook(project_path: String) -> Result<(), String> {
+    uninstall(Path::new(&project_path))
+}
+
+#[tauri::command]
+pub fn is_commit_hook_installed(project_path: String) -> Result<bool, String> {
+    Ok(is_our_hook(&hooks_dir(Path::new(&project_path))?.join(HOOK_NAME)))
+}
@@ -0,0 +1,256 @@
+use crate::backend::git::{find_commit, open, GitError};
+use git2::{Delta, Diff, DiffFindOptions, DiffOptions, Patch, Repository, Tree};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Lines of context around each hunk, as `git diff` shows by default
+const DEFAULT_CONTEXT_LINES: u32 = 3;
+
+/// What to compare
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum DiffSource {
+    /// Unstaged changes, including untracked files: index against working tree
+    WorkingTree,
+    /// Staged changes: HEAD against index
+    Index,
+    /// What a commit introduced over its first parent
+    Commit { commit: String },
+    /// Between two branches, tags or commits
+    Range { from: String, to: String },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FileChange {
+    Added,
+    Deleted,
+    Modified,
+    Renamed,
+    Copied,
+    TypeChanged,
+    Untracked,
+    Conflicted,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffLineKind {
+    Context,
+    Addition,
+    Deletion,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiffLine {
+    pub kind: DiffLineKind,
+    pub old_lineno: Option<u32>,
+    pub new_lineno: Option<u32>,
+    /// The line without its trailing newline
+    pub content: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiffHunk {
+    /// The `@@ -1,4 +1,5 @@` line, including any function context git found
+    pub header: String,
+    pub old_start: u32,
+    pub old_lines: u32,
+    pub new_start: u32,
+    pub new_lines: u32,
+    pub lines: Vec<DiffLine>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiffFile {
+    /// `None` for added files
+    pub old_path: Option<String>,
+    /// `None` for deleted files
+    pub new_path: Option<String>,
+    /// For renames and copies both paths are set and differ
+    pub change: FileChange,
+    /// Binary files have no hunks
+    pub binary: bool,
+    pub additions: usize,
+    pub deletions: usize,
+    pub hunks: Vec<DiffHunk>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiffResult {
+    pub files: Vec<DiffFile>,
+    pub additions: usize,
+    pub deletions: usize,
+}
+
+fn file_change(status: Delta) -> FileChange {
+    match status {
+        Delta::Added => FileChange::Added,
+        Delta::Deleted => FileChange::Deleted,
+        Delta::Renamed => FileChange::Renamed,
+        Delta::Copied => FileChange::Copied,
+        Delta::Typechange => FileChange::TypeChanged,
+        Delta::Untracked => FileChange::Untracked,
+        Delta::Conflicted => FileChange::Conflicted,
+        _ => FileChange::Modified,
+    }
+}
+
+fn resolve_tree<'r>(repo: &'r Repository, rev: &str) -> Result<Tree<'r>, GitError> {
+    if rev.starts_with('-') {
+        return Err(GitError::InvalidInput(format!("Invalid ref: '{}'", rev)));
+    }
+    repo.revparse_single(rev)
+        .and_then(|object| object.peel_to_tree())
+        .map_err(|_| GitError::NotFound(format!("ref {}", rev)))
+}
+
+/// HEAD's tree, or `None` before the first commit
+fn head_tree(repo: &Repository) -> Result<Option<Tree<'_>>, GitError> {
+    match repo.head() {
+        Ok(head) => Ok(Some(head.peel_to_tree()?)),
+        Err(e) if e.code() == git2::ErrorCode::UnbornBranch => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+fn build_diff<'r>(repo: &'r Repository, source: &DiffSource, options: &mut DiffOptions) -> Result<Diff<'r>, GitError> {
+    let diff = match source {
+        DiffSource::WorkingTree => {
+            options
+                .include_untracked(true)
+                .recurse_untracked_dirs(true)
+                .show_untracked_content(true);
+            repo.diff_index_to_workdir(None, Some(options))?
+        }
+        DiffSource::Index => repo.diff_tree_to_index(head_tree(repo)?.as_ref(), None, Some(options))?,
+        DiffSource::Commit { commit } => {
+            let commit = find_commit(repo, commit)?;
+            let parent_tree = match commit.parent(0) {
+                Ok(parent) => Some(parent.tree()?),
+                Err(_) => None,
+            };
+            repo.diff_tree_to_tree(parent_tree.as_ref(), Some(&commit.tree()?), Some(options))?
+        }
+        DiffSource::Range { from, to } => {
+            let from = resolve_tree(repo, from.trim())?;
+            let to = resolve_tree(repo, to.trim())?;
+            repo.diff_tree_to_tree(Some(&from), Some(&to), Some(options))?
+        }
+    };
+    Ok(diff)
+}
+
+fn path_string(path: Option<&Path>) -> Option<String> {
+    path.map(|p| p.to_string_lossy().replace('\\', "/"))
+}
+
+fn read_hunks(patch: &Patch) -> Result<Vec<DiffHunk>, GitError> {
+    let mut hunks = Vec::with_capacity(patch.num_hunks());
+    for hunk_idx in 0..patch.num_hunks() {
+        let (hunk, line_count) = patch.hunk(hunk_idx)?;
+        let mut lines = Vec::with_capacity(line_count);
+        for line_idx in 0..line_count {
+            let line = patch.line_in_hunk(hunk_idx, line_idx)?;
+            let kind = match line.origin() {
+                ' ' => DiffLineKind::Context,
+                '+' => DiffLineKind::Addition,
+                '-' => DiffLineKind::Deletion,
+                // "\ No newline at end of file" markers
+                _ => continue,
+            };
+            let content = String::from_utf8_lossy(line.content());
+            lines.push(DiffLine {
+                kind,
+                old_lineno: line.old_lineno(),
+                new_lineno: line.new_lineno(),
+                content: content.strip_suffix('\n').unwrap_or(&content).to_string(),
+            });
+        }
+        hunks.push(DiffHunk {
+            header: String::from_utf8_lossy(hunk.header()).trim_end().to_string(),
+            old_start: hunk.old_start(),
+            old_lines: hunk.old_lines(),
+            new_start: hunk.new_start(),
+            new_lines: hunk.new_lines(),
+            lines,
+        });
+    }
+    Ok(hunks)
+}
+
+/// Files, hunks and lines changed in `source`, with renames detected
+pub fn read_diff(
+    project_path: &Path,
+    source: &DiffSource,
+    paths: &[String],
+    context_lines: u32,
+) -> Result<DiffResult, GitError> {
+    let repo = open(project_path)?;
+    let mut options = DiffOptions::new();
+    options.context_lines(context_lines);
+    for path in paths.iter().map(|p| p.trim()).filter(|p| !p.is_empty()) {
+        options.pathspec(path);
+    }
+    let mut diff = build_diff(&repo, source, &mut options)?;
+    diff.find_similar(Some(DiffFindOptions::new().renames(true).for_untracked(true)))?;
+
+    let mut result = DiffResult {
+        files: Vec::with_capacity(diff.deltas().len()),
+        additions: 0,
+        deletions: 0,
+    };
+    for idx in 0..diff.deltas().len() {
+        let patch = Patch::from_diff(&diff, idx)?;
+        let Some(delta) = diff.get_delta(idx) else {
+            continue;
+        };
+        let change = file_change(delta.status());
+        let is_new = matches!(change, FileChange::Added | FileChange::Untracked);
+        let mut file = DiffFile {
+            old_path: path_string(delta.old_file().path()).filter(|_| !is_new),
+            new_path: path_string(delta.new_file().path()).filter(|_| change != FileChange::Deleted),
+            change,
+            binary: delta.flags().is_binary(),
+            additions: 0,
+            deletions: 0,
+            hunks: Vec::new(),
+        };
+        if let Some(patch) = patch {
+            // Binary detection happens while the patch is generated
+            file.binary |= patch.delta().flags().is_binary();
+            if !file.binary {
+                let (_, additions, deletions) = patch.line_stats()?;
+                file.additions = additions;
+                file.deletions = deletions;
+                file.hunks = read_hunks(&patch)?;
+            }
+        }
+        result.additions += file.additions;
+        result.deletions += file.deletions;
+        result.files.push(file);
+    }
+    Ok(result)
+}
+
+/// Structured diff of the working tree, the index, a commit or a range of refs.
+///
+/// `paths` limits the diff to matching pathspecs; `context_lines` defaults to 3.
+#[tauri::command]
+pub async fn get_diff(
+    project_path: String,
+    source: DiffSource,
+    paths: Option<Vec<String>>,
+    context_lines: Option<u32>,
+) -> Result<DiffResult, GitError> {
+    tauri::async_runtime::spawn_blocking(move || {
+        read_diff(
+            Path::new(&project_path),
+            &source,
+            &paths.unwrap_or_default(),
+            context_lines.unwrap_or(DEFAULT_CONTEXT_LINES),
+        )
+    })
+    .await
+    .map_err(|e| GitError::Git(format!("Diff failed: {}", e)))?
+}
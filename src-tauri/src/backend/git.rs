@@ -0,0 +1,382 @@
+use chrono::{DateTime, FixedOffset};
+use git2::{
+    Commit, DiffFormat, ErrorCode, IndexAddOption, Repository, RepositoryOpenFlags, Status, StatusOptions,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::Path;
+
+/// Errors from the git access layer
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", content = "message", rename_all = "snake_case")]
+pub enum GitError {
+    NotARepository(String),
+    NotFound(String),
+    InvalidInput(String),
+    /// Uncommitted changes block the operation
+    DirtyWorkingTree(String),
+    Git(String),
+}
+
+impl fmt::Display for GitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GitError::NotARepository(path) => write!(f, "{} is not a git repository", path),
+            GitError::NotFound(what) => write!(f, "Git object not found: {}", what),
+            GitError::InvalidInput(message) => write!(f, "{}", message),
+            GitError::DirtyWorkingTree(message) => write!(f, "{}", message),
+            GitError::Git(message) => write!(f, "Git error: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for GitError {}
+
+impl From<git2::Error> for GitError {
+    fn from(e: git2::Error) -> Self {
+        match e.code() {
+            ErrorCode::NotFound => GitError::NotFound(e.message().to_string()),
+            _ => GitError::Git(e.message().to_string()),
+        }
+    }
+}
+
+impl From<GitError> for String {
+    fn from(e: GitError) -> Self {
+        e.to_string()
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GitCommitInfo {
+    pub hash: String,
+    pub author: String,
+    pub email: String,
+    /// RFC 3339 author date
+    pub date: String,
+    pub summary: String,
+}
+
+/// Structured working tree and branch state for a repository
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GitSummary {
+    pub is_repo: bool,
+    /// Current branch name, `None` when HEAD is detached or unborn
+    pub branch: Option<String>,
+    pub detached: bool,
+    pub upstream: Option<String>,
+    pub ahead: usize,
+    pub behind: usize,
+    pub staged: usize,
+    pub unstaged: usize,
+    pub untracked: usize,
+    pub conflicted: usize,
+    pub stash_count: usize,
+    pub last_commit: Option<GitCommitInfo>,
+    /// Why the repository couldn't be read; the counts above are then meaningless
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+impl GitSummary {
+    /// The single-word status shown on project cards
+    pub fn status_label(&self) -> String {
+        if !self.is_repo {
+            return "none".to_string();
+        }
+        if self.error.is_some() {
+            return "error".to_string();
+        }
+        let dirty = self.staged + self.unstaged + self.untracked + self.conflicted > 0;
+        if dirty {
+            "modified"
+        } else if self.ahead > 0 && self.behind > 0 {
+            "diverged"
+        } else if self.ahead > 0 {
+            "ahead"
+        } else if self.behind > 0 {
+            "behind"
+        } else {
+            "clean"
+        }
+        .to_string()
+    }
+}
+
+pub fn is_repository(path: &Path) -> bool {
+    path.join(".git").exists()
+}
+
+/// Open the repository rooted at `path`, without searching parent directories
+pub fn open(path: &Path) -> Result<Repository, GitError> {
+    if !is_repository(path) {
+        return Err(GitError::NotARepository(path.display().to_string()));
+    }
+    Repository::open_ext(path, RepositoryOpenFlags::NO_SEARCH, std::iter::empty::<&Path>())
+        .map_err(GitError::from)
+}
+
+pub(crate) fn commit_info(commit: &Commit) -> GitCommitInfo {
+    let author = commit.author();
+    let time = author.when();
+    let date = FixedOffset::east_opt(time.offset_minutes() * 60)
+        .zip(DateTime::from_timestamp(time.seconds(), 0))
+        .map(|(offset, date)| date.with_timezone(&offset).to_rfc3339())
+        .unwrap_or_default();
+    GitCommitInfo {
+        hash: commit.id().to_string(),
+        author: author.name().unwrap_or_default().to_string(),
+        email: author.email().unwrap_or_default().to_string(),
+        date,
+        summary: commit.summary().unwrap_or_default().to_string(),
+    }
+}
+
+fn config_user_name(config: &git2::Config) -> Option<String> {
+    config
+        .get_string("user.name")
+        .ok()
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+}
+
+/// git's `user.name`, from the repository at `path` when there is one, else from the global config
+pub fn user_name(path: &Path) -> Option<String> {
+    match open(path) {
+        Ok(repo) => config_user_name(&repo.config().ok()?),
+        Err(_) => global_user_name(),
+    }
+}
+
+/// git's `user.name` from the global and system config
+pub fn global_user_name() -> Option<String> {
+    config_user_name(&git2::Config::open_default().ok()?)
+}
+
+fn missing_identity() -> GitError {
+    GitError::InvalidInput("Set git's user.name and user.email to make commits".to_string())
+}
+
+/// Fail early when git has no global `user.name` and `user.email` to commit with
+pub fn check_commit_identity() -> Result<(), GitError> {
+    let config = git2::Config::open_default()?;
+    let has = |key| config.get_string(key).map(|v| !v.trim().is_empty()).unwrap_or(false);
+    if has("user.name") && has("user.email") {
+        Ok(())
+    } else {
+        Err(missing_identity())
+    }
+}
+
+/// Create an empty repository at `path`, like `git init`
+pub fn init_repository(path: &Path) -> Result<Repository, GitError> {
+    Repository::init(path).map_err(GitError::from)
+}
+
+/// Stage every file that isn't ignored and commit it on HEAD, like `git add -A && git commit`; returns the new hash
+pub fn commit_all(repo: &Repository, message: &str) -> Result<String, GitError> {
+    let signature = repo.signature().map_err(|_| missing_identity())?;
+    let mut index = repo.index()?;
+    index.add_all(["*"], IndexAddOption::DEFAULT, None)?;
+    index.update_all(["*"], None)?;
+    index.write()?;
+    let tree = repo.find_tree(index.write_tree()?)?;
+    let parent = match repo.head() {
+        Ok(head) => Some(head.peel_to_commit()?),
+        Err(e) if e.code() == ErrorCode::UnbornBranch => None,
+        Err(e) => return Err(e.into()),
+    };
+    let parents = parent.iter().collect::<Vec<_>>();
+    let id = repo.commit(Some("HEAD"), &signature, &signature, message, &tree, &parents)?;
+    Ok(id.to_string())
+}
+
+/// Resolve a hash, branch, tag or other revision to the commit it names
+pub(crate) fn find_commit<'r>(repo: &'r Repository, rev: &str) -> Result<Commit<'r>, GitError> {
+    repo.revparse_single(rev)
+        .and_then(|object| object.peel_to_commit())
+        .map_err(|_| GitError::NotFound(format!("commit {}", rev)))
+}
+
+/// Branch, upstream and ahead/behind counts
+fn apply_head(repo: &Repository, summary: &mut GitSummary) -> Result<(), GitError> {
+    let head = match repo.head() {
+        Ok(head) => head,
+        Err(e) if e.code() == ErrorCode::UnbornBranch => {
+            // A fresh repository: HEAD names a branch without commits
+            summary.branch = repo
+                .find_reference("HEAD")?
+                .symbolic_target()
+                .and_then(|target| target.strip_prefix("refs/heads/"))
+                .map(str::to_string);
+            return Ok(());
+        }
+        Err(e) => return Err(e.into()),
+    };
+
+    summary.last_commit = head.peel_to_commit().ok().map(|commit| commit_info(&commit));
+    if repo.head_detached()? {
+        summary.detached = true;
+        return Ok(());
+    }
+    summary.branch = head.shorthand().map(str::to_string);
+
+    let Some(name) = head.name() else {
+        return Ok(());
+    };
+    let Ok(upstream) = repo.branch_upstream_name(name) else {
+        return Ok(());
+    };
+    let Some(upstream) = upstream.as_str() else {
+        return Ok(());
+    };
+    summary.upstream = Some(upstream.strip_prefix("refs/remotes/").unwrap_or(upstream).to_string());
+    if let (Some(local), Ok(remote)) = (head.target(), repo.refname_to_id(upstream)) {
+        let (ahead, behind) = repo.graph_ahead_behind(local, remote)?;
+        summary.ahead = ahead;
+        summary.behind = behind;
+    }
+    Ok(())
+}
+
+/// Staged, unstaged, untracked and conflicted entry counts, as `git status` reports them
+fn apply_status(repo: &Repository, summary: &mut GitSummary) -> Result<(), GitError> {
+    let mut options = StatusOptions::new();
+    options
+        .include_untracked(true)
+        .recurse_untracked_dirs(false)
+        .exclude_submodules(false);
+    let staged = Status::INDEX_NEW
+        | Status::INDEX_MODIFIED
+        | Status::INDEX_DELETED
+        | Status::INDEX_RENAMED
+        | Status::INDEX_TYPECHANGE;
+    let unstaged = Status::WT_MODIFIED | Status::WT_DELETED | Status::WT_RENAMED | Status::WT_TYPECHANGE;
+
+    for entry in repo.statuses(Some(&mut options))?.iter() {
+        let status = entry.status();
+        if status.is_conflicted() {
+            summary.conflicted += 1;
+            continue;
+        }
+        if status.is_wt_new() {
+            summary.untracked += 1;
+            continue;
+        }
+        if status.intersects(staged) {
+            summary.staged += 1;
+        }
+        if status.intersects(unstaged) {
+            summary.unstaged += 1;
+        }
+    }
+    Ok(())
+}
+
+/// Paths `git status` reports as changed, with their status bits; empty when the repository can't be read
+pub(crate) fn changed_entries(path: &Path) -> Vec<(String, u32)> {
+    let Ok(repo) = open(path) else {
+        return Vec::new();
+    };
+    let mut options = StatusOptions::new();
+    options.include_untracked(true).recurse_untracked_dirs(false);
+    let Ok(statuses) = repo.statuses(Some(&mut options)) else {
+        return Vec::new();
+    };
+    statuses
+        .iter()
+        .filter_map(|entry| Some((entry.path()?.to_string(), entry.status().bits())))
+        .collect()
+}
+
+/// Whether git tracks any file under `dir`, in whichever repository contains it.
+///
+/// Directories outside a repository track nothing; an unreadable repository counts as tracking them.
+pub(crate) fn contains_tracked_files(dir: &Path) -> bool {
+    let Ok(repo) = Repository::discover(dir) else {
+        return false;
+    };
+    let (Some(workdir), Ok(index)) = (repo.workdir(), repo.index()) else {
+        return true;
+    };
+    let (Ok(workdir), Ok(dir)) = (workdir.canonicalize(), dir.canonicalize()) else {
+        return true;
+    };
+    let Ok(relative) = dir.strip_prefix(&workdir) else {
+        return true;
+    };
+    let prefix = format!("{}/", relative.to_string_lossy().replace('\\', "/"));
+    index.iter().any(|entry| entry.path.starts_with(prefix.as_bytes()))
+}
+
+fn read_summary(path: &Path) -> Result<GitSummary, GitError> {
+    let mut repo = open(path)?;
+    let mut summary = GitSummary {
+        is_repo: true,
+        ..Default::default()
+    };
+    apply_head(&repo, &mut summary)?;
+    apply_status(&repo, &mut summary)?;
+    repo.stash_foreach(|_, _, _| {
+        summary.stash_count += 1;
+        true
+    })?;
+    Ok(summary)
+}
+
+/// Collect the structured git state of a project; non-repositories report `is_repo: false`
+pub fn git_summary(path: &Path) -> GitSummary {
+    match read_summary(path) {
+        Ok(summary) => summary,
+        Err(GitError::NotARepository(_)) => GitSummary::default(),
+        // A repository git2 can't read is still a repository, just not a known-clean one
+        Err(e) => GitSummary {
+            is_repo: true,
+            error: Some(e.to_string()),
+            ..Default::default()
+        },
+    }
+}
+
+/// Hash of the commit HEAD points at
+pub fn latest_commit_hash(path: &Path) -> Result<String, GitError> {
+    let repo = open(path)?;
+    let commit = repo.head()?.peel_to_commit()?;
+    Ok(commit.id().to_string())
+}
+
+/// The patch a commit introduces over its first parent, like `git diff-tree -p`
+pub fn commit_patch(path: &Path, hash: &str) -> Result<String, GitError> {
+    let repo = open(path)?;
+    let commit = find_commit(&repo, hash)?;
+    let parent_tree = match commit.parent(0) {
+        Ok(parent) => Some(parent.tree()?),
+        Err(_) => None,
+    };
+    let diff = repo.diff_tree_to_tree(parent_tree.as_ref(), Some(&commit.tree()?), None)?;
+
+    let mut patch = Vec::new();
+    diff.print(DiffFormat::Patch, |_, _, line| {
+        if matches!(line.origin(), '+' | '-' | ' ') {
+            patch.push(line.origin() as u8);
+        }
+        patch.extend_from_slice(line.content());
+        true
+    })?;
+    Ok(String::from_utf8_lossy(&patch).to_string())
+}
+
+#[tauri::command]
+pub fn get_git_summary(project_path: String) -> Result<GitSummary, String> {
+    let path = Path::new(&project_path);
+    if !path.is_dir() {
+        return Err(format!("Project directory not found: {}", project_path));
+    }
+    Ok(git_summary(path))
+}
+
+/// Hash of the latest commit on the current branch
+#[tauri::command]
+pub fn get_latest_commit_hash(project_path: String) -> Result<String, GitError> {
+    latest_commit_hash(Path::new(&project_path))
+}
@@ -0,0 +1,247 @@
+use crate::backend::get_or_create_project_uuid;
+use crate::backend::git::{find_commit, open, GitError};
+use crate::backend::timeline::{TimelineEvent, TimelineEventType};
+use crate::backend::timeline_service::TimelineService;
+use chrono::{DateTime, NaiveDate, Utc};
+use git2::{Commit, Repository, Sort};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::Path;
+use tauri::{AppHandle, Emitter, Manager};
+use uuid::Uuid;
+
+/// Commits written to the timeline per transaction, with a progress event after each batch
+const IMPORT_BATCH_SIZE: usize = 200;
+
+/// Which part of the history to import; everything reachable from HEAD by default
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct HistoryRange {
+    /// A date like `2024-01-01` or an RFC 3339 timestamp, compared with the commit date
+    pub since: Option<String>,
+    pub until: Option<String>,
+    pub branch: Option<String>,
+}
+
+/// A commit read from `git log`
+#[derive(Debug, Clone, Default)]
+pub struct HistoricCommit {
+    pub hash: String,
+    pub author: String,
+    pub email: String,
+    pub date: DateTime<Utc>,
+    pub subject: String,
+    pub body: String,
+    pub files_changed: usize,
+    pub insertions: usize,
+    pub deletions: usize,
+}
+
+/// Emitted as `git_history_import_progress` while commits are imported
+#[derive(Debug, Clone, Serialize)]
+pub struct HistoryImportProgress {
+    pub project_path: String,
+    pub processed: usize,
+    pub total: usize,
+    pub imported: usize,
+    pub skipped: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct HistoryImportResult {
+    pub total: usize,
+    pub imported: usize,
+    /// Commits already on the timeline
+    pub skipped: usize,
+}
+
+/// Parse a range bound; a bare date means the start of that day, or its end for `until`
+fn parse_bound(value: &str, end_of_day: bool) -> Result<Option<DateTime<Utc>>, String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(None);
+    }
+    if let Ok(date) = DateTime::parse_from_rfc3339(value) {
+        return Ok(Some(date.with_timezone(&Utc)));
+    }
+    let date = NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map_err(|_| format!("Invalid date: '{}'; use YYYY-MM-DD or an RFC 3339 timestamp", value))?;
+    let time = if end_of_day {
+        date.and_hms_opt(23, 59, 59)
+    } else {
+        date.and_hms_opt(0, 0, 0)
+    };
+    Ok(time.map(|t| t.and_utc()))
+}
+
+fn git_time(time: git2::Time) -> DateTime<Utc> {
+    DateTime::from_timestamp(time.seconds(), 0).unwrap_or_default()
+}
+
+/// A commit with its stats over the first parent; merges get none, as with `git log --shortstat`
+fn historic_commit(repo: &Repository, commit: &Commit) -> Result<HistoricCommit, GitError> {
+    let author = commit.author();
+    let mut historic = HistoricCommit {
+        hash: commit.id().to_string(),
+        author: author.name().unwrap_or_default().to_string(),
+        email: author.email().unwrap_or_default().to_string(),
+        date: git_time(author.when()),
+        subject: commit.summary().unwrap_or_default().to_string(),
+        body: commit.body().unwrap_or_default().trim().to_string(),
+        ..Default::default()
+    };
+    if commit.parent_count() <= 1 {
+        let parent_tree = match commit.parent(0) {
+            Ok(parent) => Some(parent.tree()?),
+            Err(_) => None,
+        };
+        let mut diff = repo.diff_tree_to_tree(parent_tree.as_ref(), Some(&commit.tree()?), None)?;
+        // Count renames as renames, not as a deletion plus an addition
+        diff.find_similar(None)?;
+        let stats = diff.stats()?;
+        historic.files_changed = stats.files_changed();
+        historic.insertions = stats.insertions();
+        historic.deletions = stats.deletions();
+    }
+    Ok(historic)
+}
+
+/// Commits in `range`, oldest first
+pub fn read_commits(path: &Path, range: &HistoryRange) -> Result<Vec<HistoricCommit>, String> {
+    let since = parse_bound(range.since.as_deref().unwrap_or_default(), false)?;
+    let until = parse_bound(range.until.as_deref().unwrap_or_default(), true)?;
+    let repo = open(path)?;
+
+    let mut walk = repo.revwalk().map_err(GitError::from)?;
+    walk.set_sorting(Sort::TIME | Sort::REVERSE).map_err(GitError::from)?;
+    match range.branch.as_deref().map(str::trim).filter(|b| !b.is_empty()) {
+        Some(branch) => {
+            let tip = find_commit(&repo, branch).map_err(|_| format!("Unknown branch or ref: '{}'", branch))?;
+            walk.push(tip.id()).map_err(GitError::from)?;
+        }
+        None => match repo.head() {
+            Ok(head) => walk.push(head.peel_to_commit().map_err(GitError::from)?.id()).map_err(GitError::from)?,
+            // Nothing committed yet
+            Err(e) if e.code() == git2::ErrorCode::UnbornBranch => return Ok(Vec::new()),
+            Err(e) => return Err(GitError::from(e).into()),
+        },
+    }
+
+    let mut commits = Vec::new();
+    for oid in walk {
+        let commit = repo.find_commit(oid.map_err(GitError::from)?).map_err(GitError::from)?;
+        let committed = git_time(commit.time());
+        if since.map(|s| committed < s).unwrap_or(false) || until.map(|u| committed > u).unwrap_or(false) {
+            continue;
+        }
+        commits.push(historic_commit(&repo, &commit)?);
+    }
+    Ok(commits)
+}
+
+/// A single commit, with its stats
+pub fn read_commit(path: &Path, hash: &str) -> Result<HistoricCommit, String> {
+    let repo = open(path)?;
+    let commit = find_commit(&repo, hash)?;
+    Ok(historic_commit(&repo, &commit)?)
+}
+
+/// Build the timeline event for an imported commit, dated when it was authored
+pub fn commit_event(project_id: Uuid, commit: &HistoricCommit) -> TimelineEvent {
+    let mut metadata = HashMap::new();
+    metadata.insert("author".to_string(), Value::String(commit.author.clone()));
+    metadata.insert("email".to_string(), Value::String(commit.email.clone()));
+    metadata.insert("files_changed".to_string(), Value::from(commit.files_changed));
+    metadata.insert("insertions".to_string(), Value::from(commit.insertions));
+    metadata.insert("deletions".to_string(), Value::from(commit.deletions));
+
+    let description = if commit.body.is_empty() {
+        commit.subject.clone()
+    } else {
+        format!("{}\n\n{}", commit.subject, commit.body)
+    };
+    TimelineEvent {
+        id: Uuid::new_v4(),
+        project_id,
+        timestamp: commit.date,
+        event_type: TimelineEventType::GitCommit {
+            hash: commit.hash.clone(),
+            message: commit.subject.clone(),
+        },
+        title: format!("Commit {}", &commit.hash[..7.min(commit.hash.len())]),
+        description: Some(description),
+        metadata,
+        user_id: None,
+        tags: vec!["commit".to_string(), "imported".to_string()],
+    }
+}
+
+/// Add commits in `range` to a project's timeline, skipping ones it already has.
+///
+/// Emits `git_history_import_progress` after every batch.
+pub fn import_history(
+    app_handle: &AppHandle,
+    project_id: Uuid,
+    path: &Path,
+    range: &HistoryRange,
+) -> Result<HistoryImportResult, String> {
+    let commits = read_commits(path, range)?;
+    let timeline_service = app_handle
+        .try_state::<TimelineService>()
+        .ok_or("Timeline service is not available")?;
+    let known = timeline_service
+        .commit_hashes(project_id)
+        .map_err(|e| format!("Failed to read timeline: {}", e))?;
+
+    let mut progress = HistoryImportProgress {
+        project_path: path.to_string_lossy().to_string(),
+        processed: 0,
+        total: commits.len(),
+        imported: 0,
+        skipped: 0,
+    };
+    let _ = app_handle.emit("git_history_import_progress", &progress);
+    for batch in commits.chunks(IMPORT_BATCH_SIZE) {
+        let events = batch
+            .iter()
+            .filter(|commit| !known.contains(&commit.hash))
+            .map(|commit| commit_event(project_id, commit))
+            .collect::<Vec<_>>();
+        timeline_service
+            .add_events(&events)
+            .map_err(|e| format!("Failed to import commits: {}", e))?;
+
+        progress.processed += batch.len();
+        progress.imported += events.len();
+        progress.skipped += batch.len() - events.len();
+        let _ = app_handle.emit("git_history_import_progress", &progress);
+    }
+
+    Ok(HistoryImportResult {
+        total: progress.total,
+        imported: progress.imported,
+        skipped: progress.skipped,
+    })
+}
+
+/// Backfill a project's timeline from `git log`; re-importing only adds commits not seen before
+#[tauri::command]
+pub async fn import_git_history(
+    app_handle: AppHandle,
+    project_path: String,
+    since: Option<String>,
+    until: Option<String>,
+    branch: Option<String>,
+) -> Result<HistoryImportResult, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let path = Path::new(&project_path);
+        if !path.join(".git").exists() {
+            return Err(format!("{} is not a git repository", project_path));
+        }
+        let project_id = Uuid::parse_str(&get_or_create_project_uuid(project_path.clone())?)
+            .map_err(|e| e.to_string())?;
+        import_history(&app_handle, project_id, path, &HistoryRange { since, until, branch })
+    })
+    .await
+    .map_err(|e| format!("History import failed: {}", e))?
+}
@@ -0,0 +1,146 @@
+use ignore::WalkBuilder;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Seed contents of the app-level ignore list, in .gitignore syntax
+const DEFAULT_GLOBAL_IGNORES: &[&str] = &[
+    "node_modules/",
+    "target/",
+    "dist/",
+    "build/",
+    "out/",
+    ".next/",
+    ".nuxt/",
+    ".svelte-kit/",
+    ".turbo/",
+    ".cache/",
+    ".parcel-cache/",
+    ".venv/",
+    "venv/",
+    "__pycache__/",
+    ".pytest_cache/",
+    ".mypy_cache/",
+    ".gradle/",
+];
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DirectoryStats {
+    pub size: u64,
+    pub files_count: usize,
+    /// Bytes and files excluded by ignore rules; only filled in when requested
+    pub ignored_size: Option<u64>,
+    pub ignored_files: Option<usize>,
+}
+
+pub(crate) fn global_ignore_path() -> Result<PathBuf, String> {
+    let mut path = dirs::data_dir().ok_or("Could not find data directory")?;
+    path.push("project-manager");
+    fs::create_dir_all(&path).map_err(|e| format!("Failed to create data dir: {}", e))?;
+    path.push("ignore");
+    Ok(path)
+}
+
+/// Path of the app-level ignore file, creating it with defaults on first use
+fn ensure_global_ignore_file() -> Result<PathBuf, String> {
+    let path = global_ignore_path()?;
+    if !path.exists() {
+        let mut contents = DEFAULT_GLOBAL_IGNORES.join("\n");
+        contents.push('\n');
+        fs::write(&path, contents).map_err(|e| format!("Failed to write ignore file: {}", e))?;
+    }
+    Ok(path)
+}
+
+/// Walker over a project that honors .gitignore, .ignore and the app-level ignore list.
+///
+/// Hidden files are included; `.git` itself is always skipped.
+pub fn project_walker(root: &Path) -> WalkBuilder {
+    let mut builder = WalkBuilder::new(root);
+    builder
+        .hidden(false)
+        .parents(false)
+        .require_git(false)
+        .filter_entry(|entry| entry.file_name() != ".git");
+    if let Ok(global) = ensure_global_ignore_file() {
+        builder.add_ignore(global);
+    }
+    builder
+}
+
+/// Walker over every file in a project except `.git`
+fn unfiltered_walker(root: &Path) -> WalkBuilder {
+    let mut builder = WalkBuilder::new(root);
+    builder
+        .standard_filters(false)
+        .filter_entry(|entry| entry.file_name() != ".git");
+    builder
+}
+
+fn sum_files(builder: &WalkBuilder) -> (u64, usize) {
+    let mut size = 0;
+    let mut count = 0;
+    for entry in builder.build().filter_map(|e| e.ok()) {
+        if entry.file_type().map(|t| t.is_file()).unwrap_or(false) {
+            size += entry.metadata().map(|m| m.len()).unwrap_or(0);
+            count += 1;
+        }
+    }
+    (size, count)
+}
+
+/// Size and file count of everything under `path`, ignored files included
+pub(crate) fn total_size(path: &Path) -> (u64, usize) {
+    sum_files(&unfiltered_walker(path))
+}
+
+/// Size and file count of a project, optionally with the ignored remainder
+pub fn calculate_directory_stats(path: &Path, include_ignored: bool) -> Result<DirectoryStats, String> {
+    if !path.is_dir() {
+        return Err(format!("Failed to calculate directory stats: {} is not a directory", path.display()));
+    }
+
+    let (size, files_count) = sum_files(&project_walker(path));
+    let mut stats = DirectoryStats {
+        size,
+        files_count,
+        ..Default::default()
+    };
+
+    if include_ignored {
+        let (total_size, total_files) = sum_files(&unfiltered_walker(path));
+        stats.ignored_size = Some(total_size.saturating_sub(size));
+        stats.ignored_files = Some(total_files.saturating_sub(files_count));
+    }
+
+    Ok(stats)
+}
+
+#[tauri::command]
+pub fn get_directory_stats(project_path: String, include_ignored: bool) -> Result<DirectoryStats, String> {
+    calculate_directory_stats(Path::new(&project_path), include_ignored)
+}
+
+#[tauri::command]
+pub fn get_global_ignore_patterns() -> Result<Vec<String>, String> {
+    let path = ensure_global_ignore_file()?;
+    let data = fs::read_to_string(path).map_err(|e| format!("Failed to read ignore file: {}", e))?;
+    Ok(data
+        .lines()
+        .map(|l| l.trim().to_string())
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .collect())
+}
+
+#[tauri::command]
+pub fn set_global_ignore_patterns(patterns: Vec<String>) -> Result<(), String> {
+    let path = global_ignore_path()?;
+    let mut contents = patterns
+        .iter()
+        .map(|p| p.trim())
+        .filter(|p| !p.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n");
+    contents.push('\n');
+    fs::write(path, contents).map_err(|e| format!("Failed to write ignore file: {}", e))
+}
@@ -31,6 +31,8 @@ impl KanbanService {
             CREATE INDEX IF NOT EXISTS idx_kanban_project_id ON kanban_tasks(project_id);
             CREATE INDEX IF NOT EXISTS idx_kanban_status ON kanban_tasks(status);
         "#).unwrap();
+        // Databases created before soft deletion lack the column; the error for an existing one is expected
+        let _ = conn.execute("ALTER TABLE kanban_tasks ADD COLUMN deleted_at TEXT", []);
         Self { db: Arc::new(Mutex::new(conn)) }
     }
 
@@ -86,9 +88,27 @@ impl KanbanService {
         Ok(())
     }
 
+    /// Hide or unhide every task of a project that was moved to the trash
+    pub fn set_project_deleted(&self, project_id: Uuid, deleted: bool) -> SqlResult<usize> {
+        let db = self.db.lock().unwrap();
+        let deleted_at = deleted.then(|| Utc::now().to_rfc3339());
+        db.execute(
+            "UPDATE kanban_tasks SET deleted_at = ?2 WHERE project_id = ?1",
+            params![project_id.to_string(), deleted_at],
+        )
+    }
+
+    pub fn purge_project(&self, project_id: Uuid) -> SqlResult<usize> {
+        let db = self.db.lock().unwrap();
+        db.execute(
+            "DELETE FROM kanban_tasks WHERE project_id = ?1",
+            params![project_id.to_string()],
+        )
+    }
+
     pub fn get_project_tasks(&self, project_id: Uuid) -> SqlResult<Vec<KanbanTask>> {
         let db = self.db.lock().unwrap();
-        let mut stmt = db.prepare("SELECT * FROM kanban_tasks WHERE project_id = ?1 ORDER BY created_at DESC")?;
+        let mut stmt = db.prepare("SELECT * FROM kanban_tasks WHERE project_id = ?1 AND deleted_at IS NULL ORDER BY created_at DESC")?;
         let mut rows = stmt.query(params![project_id.to_string()])?;
 
         let mut tasks = Vec::new();
@@ -0,0 +1,169 @@
+use crate::backend::ignore_rules::project_walker;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// Files larger than this are assumed to be generated or vendored and skipped
+const MAX_FILE_SIZE: u64 = 1024 * 1024;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LanguageStats {
+    pub language: String,
+    pub files: usize,
+    pub code: usize,
+    pub comments: usize,
+    pub blanks: usize,
+}
+
+struct LanguageDef {
+    name: &'static str,
+    extensions: &'static [&'static str],
+    file_names: &'static [&'static str],
+    line_comments: &'static [&'static str],
+    block_comments: &'static [(&'static str, &'static str)],
+}
+
+const C_BLOCK: &[(&str, &str)] = &[("/*", "*/")];
+const HTML_BLOCK: &[(&str, &str)] = &[("<!--", "-->")];
+
+const LANGUAGES: &[LanguageDef] = &[
+    LanguageDef { name: "Rust", extensions: &["rs"], file_names: &[], line_comments: &["//"], block_comments: C_BLOCK },
+    LanguageDef { name: "TypeScript", extensions: &["ts", "tsx", "mts", "cts"], file_names: &[], line_comments: &["//"], block_comments: C_BLOCK },
+    LanguageDef { name: "JavaScript", extensions: &["js", "jsx", "mjs", "cjs"], file_names: &[], line_comments: &["//"], block_comments: C_BLOCK },
+    LanguageDef { name: "Python", extensions: &["py", "pyi"], file_names: &[], line_comments: &["#"], block_comments: &[("\"\"\"", "\"\"\"")] },
+    LanguageDef { name: "Go", extensions: &["go"], file_names: &[], line_comments: &["//"], block_comments: C_BLOCK },
+    LanguageDef { name: "C#", extensions: &["cs"], file_names: &[], line_comments: &["//"], block_comments: C_BLOCK },
+    LanguageDef { name: "F#", extensions: &["fs", "fsx"], file_names: &[], line_comments: &["//"], block_comments: &[("(*", "*)")] },
+    LanguageDef { name: "Java", extensions: &["java"], file_names: &[], line_comments: &["//"], block_comments: C_BLOCK },
+    LanguageDef { name: "Kotlin", extensions: &["kt", "kts"], file_names: &[], line_comments: &["//"], block_comments: C_BLOCK },
+    LanguageDef { name: "Swift", extensions: &["swift"], file_names: &[], line_comments: &["//"], block_comments: C_BLOCK },
+    LanguageDef { name: "Dart", extensions: &["dart"], file_names: &[], line_comments: &["//"], block_comments: C_BLOCK },
+    LanguageDef { name: "C", extensions: &["c", "h"], file_names: &[], line_comments: &["//"], block_comments: C_BLOCK },
+    LanguageDef { name: "C++", extensions: &["cpp", "cc", "cxx", "hpp", "hh", "hxx"], file_names: &[], line_comments: &["//"], block_comments: C_BLOCK },
+    LanguageDef { name: "PHP", extensions: &["php"], file_names: &[], line_comments: &["//", "#"], block_comments: C_BLOCK },
+    LanguageDef { name: "Ruby", extensions: &["rb"], file_names: &["Gemfile", "Rakefile"], line_comments: &["#"], block_comments: &[("=begin", "=end")] },
+    LanguageDef { name: "Shell", extensions: &["sh", "bash", "zsh"], file_names: &[], line_comments: &["#"], block_comments: &[] },
+    LanguageDef { name: "PowerShell", extensions: &["ps1", "psm1"], file_names: &[], line_comments: &["#"], block_comments: &[("<#", "#>")] },
+    LanguageDef { name: "SQL", extensions: &["sql"], file_names: &[], line_comments: &["--"], block_comments: C_BLOCK },
+    LanguageDef { name: "HTML", extensions: &["html", "htm"], file_names: &[], line_comments: &[], block_comments: HTML_BLOCK },
+    LanguageDef { name: "Vue", extensions: &["vue"], file_names: &[], line_comments: &["//"], block_comments: &[("<!--", "-->"), ("/*", "*/")] },
+    LanguageDef { name: "Svelte", extensions: &["svelte"], file_names: &[], line_comments: &["//"], block_comments: &[("<!--", "-->"), ("/*", "*/")] },
+    LanguageDef { name: "CSS", extensions: &["css"], file_names: &[], line_comments: &[], block_comments: C_BLOCK },
+    LanguageDef { name: "SCSS", extensions: &["scss", "sass"], file_names: &[], line_comments: &["//"], block_comments: C_BLOCK },
+    LanguageDef { name: "Less", extensions: &["less"], file_names: &[], line_comments: &["//"], block_comments: C_BLOCK },
+    LanguageDef { name: "Markdown", extensions: &["md", "mdx"], file_names: &[], line_comments: &[], block_comments: HTML_BLOCK },
+    LanguageDef { name: "JSON", extensions: &["json"], file_names: &[], line_comments: &[], block_comments: &[] },
+    LanguageDef { name: "TOML", extensions: &["toml"], file_names: &[], line_comments: &["#"], block_comments: &[] },
+    LanguageDef { name: "YAML", extensions: &["yml", "yaml"], file_names: &[], line_comments: &["#"], block_comments: &[] },
+    LanguageDef { name: "XML", extensions: &["xml", "csproj", "fsproj", "props", "xaml"], file_names: &[], line_comments: &[], block_comments: HTML_BLOCK },
+    LanguageDef { name: "Razor", extensions: &["razor", "cshtml"], file_names: &[], line_comments: &[], block_comments: &[("@*", "*@"), ("<!--", "-->")] },
+    LanguageDef { name: "Dockerfile", extensions: &[], file_names: &["Dockerfile"], line_comments: &["#"], block_comments: &[] },
+    LanguageDef { name: "Makefile", extensions: &["mk"], file_names: &["Makefile", "makefile"], line_comments: &["#"], block_comments: &[] },
+];
+
+fn language_for(path: &Path) -> Option<&'static LanguageDef> {
+    let file_name = path.file_name()?.to_string_lossy();
+    if let Some(lang) = LANGUAGES.iter().find(|l| l.file_names.contains(&file_name.as_ref())) {
+        return Some(lang);
+    }
+    let ext = path.extension()?.to_string_lossy().to_lowercase();
+    LANGUAGES.iter().find(|l| l.extensions.contains(&ext.as_str()))
+}
+
+/// Classify each line of `source` as code, comment or blank
+fn count_lines(lang: &LanguageDef, source: &str, stats: &mut LanguageStats) {
+    let mut open_block: Option<&str> = None;
+
+    for line in source.lines() {
+        let trimmed = line.trim();
+
+        if let Some(end) = open_block {
+            if let Some(pos) = trimmed.find(end) {
+                open_block = None;
+                if trimmed[pos + end.len()..].trim().is_empty() {
+                    stats.comments += 1;
+                } else {
+                    stats.code += 1;
+                }
+            } else {
+                stats.comments += 1;
+            }
+            continue;
+        }
+
+        if trimmed.is_empty() {
+            stats.blanks += 1;
+            continue;
+        }
+
+        if lang.line_comments.iter().any(|c| trimmed.starts_with(c)) {
+            stats.comments += 1;
+            continue;
+        }
+
+        if let Some((start, end)) = lang.block_comments.iter().find(|(s, _)| trimmed.starts_with(s)) {
+            let rest = &trimmed[start.len()..];
+            match rest.find(end) {
+                Some(pos) if !rest[pos + end.len()..].trim().is_empty() => stats.code += 1,
+                Some(_) => stats.comments += 1,
+                None => {
+                    open_block = Some(end);
+                    stats.comments += 1;
+                }
+            }
+            continue;
+        }
+
+        stats.code += 1;
+        // A block comment opened after code on the same line
+        for (start, end) in lang.block_comments {
+            if let Some(pos) = trimmed.find(start) {
+                if !trimmed[pos + start.len()..].contains(end) {
+                    open_block = Some(end);
+                }
+                break;
+            }
+        }
+    }
+}
+
+/// Per-language file and line counts for a project, largest first
+pub fn count_languages(path: &Path) -> Vec<LanguageStats> {
+    let mut stats: HashMap<&'static str, LanguageStats> = HashMap::new();
+
+    for entry in project_walker(path).build().filter_map(|e| e.ok()) {
+        if !entry.file_type().map(|t| t.is_file()).unwrap_or(false) {
+            continue;
+        }
+        let Some(lang) = language_for(entry.path()) else {
+            continue;
+        };
+        if entry.metadata().map(|m| m.len() > MAX_FILE_SIZE).unwrap_or(true) {
+            continue;
+        }
+        // Non-UTF-8 files are treated as binary
+        let Ok(source) = fs::read_to_string(entry.path()) else {
+            continue;
+        };
+        let lang_stats = stats.entry(lang.name).or_insert_with(|| LanguageStats {
+            language: lang.name.to_string(),
+            ..Default::default()
+        });
+        lang_stats.files += 1;
+        count_lines(lang, &source, lang_stats);
+    }
+
+    let mut languages = stats.into_values().collect::<Vec<_>>();
+    languages.sort_by(|a, b| b.code.cmp(&a.code).then_with(|| a.language.cmp(&b.language)));
+    languages
+}
+
+#[tauri::command]
+pub fn get_project_languages(project_path: String) -> Result<Vec<LanguageStats>, String> {
+    let path = Path::new(&project_path);
+    if !path.is_dir() {
+        return Err(format!("Project directory not found: {}", project_path));
+    }
+    Ok(count_languages(path))
+}
@@ -0,0 +1,481 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum DependencyKind {
+    Runtime,
+    Dev,
+    Build,
+    Peer,
+    Optional,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum DependencySource {
+    Registry,
+    Git,
+    Path,
+    Url,
+    Workspace,
+}
+
+/// A dependency declared by one of the project's manifests
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DeclaredDependency {
+    pub name: String,
+    pub version_req: Option<String>,
+    pub kind: DependencyKind,
+    pub source: DependencySource,
+    /// `cargo`, `npm`, `pypi`, `go` or `nuget`
+    pub ecosystem: String,
+    /// Manifest file the dependency was read from, relative to the project
+    pub manifest: String,
+}
+
+impl DeclaredDependency {
+    fn new(name: &str, version_req: Option<String>, kind: DependencyKind, ecosystem: &str, manifest: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            version_req: version_req.filter(|v| !v.is_empty()),
+            kind,
+            source: DependencySource::Registry,
+            ecosystem: ecosystem.to_string(),
+            manifest: manifest.to_string(),
+        }
+    }
+
+    fn with_source(mut self, source: DependencySource) -> Self {
+        self.source = source;
+        self
+    }
+}
+
+/// Every dependency declared by the manifests in the project root
+pub fn parse_manifests(root: &Path) -> Vec<DeclaredDependency> {
+    let mut deps = Vec::new();
+
+    if let Ok(data) = fs::read_to_string(root.join("package.json")) {
+        deps.extend(parse_package_json(&data));
+    }
+    if let Ok(data) = fs::read_to_string(root.join("Cargo.toml")) {
+        deps.extend(parse_cargo_toml(&data, "Cargo.toml"));
+    }
+    if let Ok(data) = fs::read_to_string(root.join("src-tauri/Cargo.toml")) {
+        deps.extend(parse_cargo_toml(&data, "src-tauri/Cargo.toml"));
+    }
+    if let Ok(data) = fs::read_to_string(root.join("pyproject.toml")) {
+        deps.extend(parse_pyproject(&data));
+    }
+    for (file, kind) in [
+        ("requirements.txt", DependencyKind::Runtime),
+        ("requirements-dev.txt", DependencyKind::Dev),
+        ("dev-requirements.txt", DependencyKind::Dev),
+    ] {
+        if let Ok(data) = fs::read_to_string(root.join(file)) {
+            deps.extend(parse_requirements(&data, kind, file));
+        }
+    }
+    if let Ok(data) = fs::read_to_string(root.join("go.mod")) {
+        deps.extend(parse_go_mod(&data));
+    }
+    if let Ok(entries) = fs::read_dir(root) {
+        let mut projects = entries
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| p.extension().map(|e| e == "csproj" || e == "fsproj").unwrap_or(false))
+            .collect::<Vec<_>>();
+        projects.sort();
+        for path in projects {
+            if let Ok(data) = fs::read_to_string(&path) {
+                let manifest = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+                deps.extend(parse_msbuild_project(&data, &manifest));
+            }
+        }
+    }
+
+    deps
+}
+
+fn npm_source(version: &str) -> DependencySource {
+    if version.starts_with("workspace:") {
+        DependencySource::Workspace
+    } else if version.starts_with("file:") || version.starts_with("link:") {
+        DependencySource::Path
+    } else if version.starts_with("git")
+        || version.starts_with("github:")
+        || version.contains(".git")
+    {
+        DependencySource::Git
+    } else if version.starts_with("http://") || version.starts_with("https://") {
+        DependencySource::Url
+    } else {
+        DependencySource::Registry
+    }
+}
+
+fn parse_package_json(data: &str) -> Vec<DeclaredDependency> {
+    let Ok(json) = serde_json::from_str::<serde_json::Value>(data) else {
+        return Vec::new();
+    };
+    let mut deps = Vec::new();
+    for (section, kind) in [
+        ("dependencies", DependencyKind::Runtime),
+        ("devDependencies", DependencyKind::Dev),
+        ("peerDependencies", DependencyKind::Peer),
+        ("optionalDependencies", DependencyKind::Optional),
+    ] {
+        if let Some(entries) = json[section].as_object() {
+            for (name, version) in entries {
+                let version = version.as_str().unwrap_or_default().to_string();
+                deps.push(
+                    DeclaredDependency::new(name, Some(version.clone()), kind.clone(), "npm", "package.json")
+                        .with_source(npm_source(&version)),
+                );
+            }
+        }
+    }
+    deps
+}
+
+fn cargo_dependency(name: &str, spec: &toml::Value, kind: DependencyKind, manifest: &str) -> DeclaredDependency {
+    let Some(table) = spec.as_table() else {
+        return DeclaredDependency::new(name, spec.as_str().map(|s| s.to_string()), kind, "cargo", manifest);
+    };
+    let version = table.get("version").and_then(|v| v.as_str()).map(|s| s.to_string());
+    let source = if table.get("workspace").and_then(|v| v.as_bool()).unwrap_or(false) {
+        DependencySource::Workspace
+    } else if table.contains_key("git") {
+        DependencySource::Git
+    } else if table.contains_key("path") {
+        DependencySource::Path
+    } else {
+        DependencySource::Registry
+    };
+    let kind = if kind == DependencyKind::Runtime
+        && table.get("optional").and_then(|v| v.as_bool()).unwrap_or(false)
+    {
+        DependencyKind::Optional
+    } else {
+        kind
+    };
+    // A `package = "..."` rename points at the real crate name
+    let name = table.get("package").and_then(|v| v.as_str()).unwrap_or(name);
+    DeclaredDependency::new(name, version, kind, "cargo", manifest).with_source(source)
+}
+
+fn parse_cargo_sections(table: &toml::Table, manifest: &str, deps: &mut Vec<DeclaredDependency>) {
+    for (section, kind) in [
+        ("dependencies", DependencyKind::Runtime),
+        ("dev-dependencies", DependencyKind::Dev),
+        ("build-dependencies", DependencyKind::Build),
+    ] {
+        if let Some(entries) = table.get(section).and_then(|v| v.as_table()) {
+            for (name, spec) in entries {
+                deps.push(cargo_dependency(name, spec, kind.clone(), manifest));
+            }
+        }
+    }
+}
+
+fn parse_cargo_toml(data: &str, manifest: &str) -> Vec<DeclaredDependency> {
+    let Ok(table) = toml::from_str::<toml::Table>(data) else {
+        return Vec::new();
+    };
+    let mut deps = Vec::new();
+    parse_cargo_sections(&table, manifest, &mut deps);
+
+    // [target.'cfg(...)'.dependencies]
+    if let Some(targets) = table.get("target").and_then(|v| v.as_table()) {
+        for target in targets.values().filter_map(|t| t.as_table()) {
+            parse_cargo_sections(target, manifest, &mut deps);
+        }
+    }
+    if let Some(entries) = table
+        .get("workspace")
+        .and_then(|w| w.get("dependencies"))
+        .and_then(|v| v.as_table())
+    {
+        for (name, spec) in entries {
+            deps.push(cargo_dependency(name, spec, DependencyKind::Runtime, manifest));
+        }
+    }
+    deps
+}
+
+/// Where a bare URL or path requirement points, or `None` for a named requirement
+fn direct_reference_source(requirement: &str) -> Option<DependencySource> {
+    if ["git+", "hg+", "svn+", "bzr+"].iter().any(|p| requirement.starts_with(p)) {
+        Some(if requirement.starts_with("git+") { DependencySource::Git } else { DependencySource::Url })
+    } else if requirement.starts_with("http://") || requirement.starts_with("https://") {
+        Some(DependencySource::Url)
+    } else if ["file:", ".", "/"].iter().any(|p| requirement.starts_with(p)) {
+        Some(DependencySource::Path)
+    } else {
+        None
+    }
+}
+
+/// A requirement given as a URL or path, e.g. `git+https://host/repo.git#egg=name` or `./libs/name`.
+///
+/// The name comes from the `#egg=` fragment, or a local directory's name; URLs without one are skipped.
+fn parse_direct_reference(
+    requirement: &str,
+    source: DependencySource,
+    kind: DependencyKind,
+    manifest: &str,
+) -> Option<DeclaredDependency> {
+    let (location, fragment) = requirement.split_once('#').unwrap_or((requirement, ""));
+    let egg = fragment
+        .split('&')
+        .find_map(|part| part.strip_prefix("egg="))
+        .map(|egg| egg.split(['[', '=', '<', '>', '~', '!']).next().unwrap_or(egg));
+    let name = match egg {
+        Some(egg) => egg,
+        None if source == DependencySource::Path => {
+            let location = location.strip_prefix("file://").or_else(|| location.strip_prefix("file:")).unwrap_or(location);
+            Path::new(location).file_name()?.to_str()?
+        }
+        None => return None,
+    };
+    if name.is_empty() {
+        return None;
+    }
+    Some(DeclaredDependency::new(name, None, kind, "pypi", manifest).with_source(source))
+}
+
+/// Split a PEP 508 requirement such as `requests[socks]>=2.31; python_version > "3.8"`
+fn parse_pep508(requirement: &str, kind: DependencyKind, manifest: &str) -> Option<DeclaredDependency> {
+    let requirement = requirement.split(';').next()?.trim();
+    if let Some(source) = direct_reference_source(requirement) {
+        return parse_direct_reference(requirement, source, kind, manifest);
+    }
+    let name_end = requirement
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.'))
+        .unwrap_or(requirement.len());
+    let name = &requirement[..name_end];
+    if name.is_empty() {
+        return None;
+    }
+    let mut rest = requirement[name_end..].trim();
+    if rest.starts_with('[') {
+        rest = rest.split_once(']').map(|(_, r)| r.trim()).unwrap_or("");
+    }
+    if let Some(url) = rest.strip_prefix('@') {
+        let url = url.trim();
+        let source = if url.starts_with("git+") {
+            DependencySource::Git
+        } else if url.starts_with("file:") {
+            DependencySource::Path
+        } else {
+            DependencySource::Url
+        };
+        return Some(DeclaredDependency::new(name, None, kind, "pypi", manifest).with_source(source));
+    }
+    let version = rest.trim_matches(|c| c == '(' || c == ')').trim().to_string();
+    Some(DeclaredDependency::new(name, Some(version), kind, "pypi", manifest))
+}
+
+fn parse_requirements(data: &str, kind: DependencyKind, manifest: &str) -> Vec<DeclaredDependency> {
+    data.lines()
+        .map(|line| line.split(" #").next().unwrap_or(line).trim())
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            // Editable installs are the only option lines that name a dependency
+            let line = match line.strip_prefix("--editable").or_else(|| line.strip_prefix("-e")) {
+                Some(target) => target.trim_start_matches([' ', '=']),
+                None if line.starts_with('-') => return None,
+                None => line,
+            };
+            parse_pep508(line, kind.clone(), manifest)
+        })
+        .collect()
+}
+
+fn parse_pyproject(data: &str) -> Vec<DeclaredDependency> {
+    let Ok(table) = toml::from_str::<toml::Table>(data) else {
+        return Vec::new();
+    };
+    let manifest = "pyproject.toml";
+    let mut deps = Vec::new();
+    let strings = |value: Option<&toml::Value>| {
+        value
+            .and_then(|v| v.as_array())
+            .map(|items| items.iter().filter_map(|i| i.as_str().map(|s| s.to_string())).collect::<Vec<_>>())
+            .unwrap_or_default()
+    };
+
+    // PEP 621
+    if let Some(project) = table.get("project").and_then(|v| v.as_table()) {
+        for req in strings(project.get("dependencies")) {
+            deps.extend(parse_pep508(&req, DependencyKind::Runtime, manifest));
+        }
+        if let Some(groups) = project.get("optional-dependencies").and_then(|v| v.as_table()) {
+            for group in groups.values() {
+                for req in strings(Some(group)) {
+                    deps.extend(parse_pep508(&req, DependencyKind::Optional, manifest));
+                }
+            }
+        }
+    }
+    // PEP 735
+    if let Some(groups) = table.get("dependency-groups").and_then(|v| v.as_table()) {
+        for group in groups.values() {
+            for req in strings(Some(group)) {
+                deps.extend(parse_pep508(&req, DependencyKind::Dev, manifest));
+            }
+        }
+    }
+    if let Some(build) = table.get("build-system").and_then(|v| v.as_table()) {
+        for req in strings(build.get("requires")) {
+            deps.extend(parse_pep508(&req, DependencyKind::Build, manifest));
+        }
+    }
+
+    // Poetry
+    if let Some(poetry) = table
+        .get("tool")
+        .and_then(|t| t.get("poetry"))
+        .and_then(|v| v.as_table())
+    {
+        let mut poetry_section = |entries: &toml::Table, kind: DependencyKind| {
+            for (name, spec) in entries.iter().filter(|(name, _)| name.as_str() != "python") {
+                let (version, source) = match spec {
+                    toml::Value::String(v) => (Some(v.clone()), DependencySource::Registry),
+                    toml::Value::Table(t) => (
+                        t.get("version").and_then(|v| v.as_str()).map(|s| s.to_string()),
+                        if t.contains_key("git") {
+                            DependencySource::Git
+                        } else if t.contains_key("path") {
+                            DependencySource::Path
+                        } else if t.contains_key("url") {
+                            DependencySource::Url
+                        } else {
+                            DependencySource::Registry
+                        },
+                    ),
+                    _ => (None, DependencySource::Registry),
+                };
+                deps.push(DeclaredDependency::new(name, version, kind.clone(), "pypi", manifest).with_source(source));
+            }
+        };
+        if let Some(entries) = poetry.get("dependencies").and_then(|v| v.as_table()) {
+            poetry_section(entries, DependencyKind::Runtime);
+        }
+        if let Some(entries) = poetry.get("dev-dependencies").and_then(|v| v.as_table()) {
+            poetry_section(entries, DependencyKind::Dev);
+        }
+        if let Some(groups) = poetry.get("group").and_then(|v| v.as_table()) {
+            for group in groups.values() {
+                if let Some(entries) = group.get("dependencies").and_then(|v| v.as_table()) {
+                    poetry_section(entries, DependencyKind::Dev);
+                }
+            }
+        }
+    }
+
+    deps
+}
+
+fn parse_go_mod(data: &str) -> Vec<DeclaredDependency> {
+    let mut deps = Vec::new();
+    let mut replaced_with_path = Vec::new();
+    let mut block: Option<&str> = None;
+
+    for line in data.lines() {
+        let line = line.split("//").next().unwrap_or(line).trim();
+        if line.is_empty() {
+            continue;
+        }
+        if line == ")" {
+            block = None;
+            continue;
+        }
+        let (directive, body) = match block {
+            Some(directive) => (directive, line),
+            None => {
+                let (directive, body) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+                if body.trim() == "(" {
+                    block = Some(directive);
+                    continue;
+                }
+                (directive, body.trim())
+            }
+        };
+        match directive {
+            "require" => {
+                let mut parts = body.split_whitespace();
+                if let Some(module) = parts.next() {
+                    let version = parts.next().map(|v| v.to_string());
+                    deps.push(DeclaredDependency::new(module, version, DependencyKind::Runtime, "go", "go.mod"));
+                }
+            }
+            "replace" => {
+                if let Some((old, new)) = body.split_once("=>") {
+                    let target = new.trim();
+                    if target.starts_with('.') || target.starts_with('/') {
+                        let module = old.split_whitespace().next().unwrap_or_default();
+                        replaced_with_path.push(module.to_string());
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    for dep in deps.iter_mut() {
+        if replaced_with_path.contains(&dep.name) {
+            dep.source = DependencySource::Path;
+        }
+    }
+    deps
+}
+
+fn xml_attribute(element: &str, attribute: &str) -> Option<String> {
+    let key = format!("{}=\"", attribute);
+    let start = element.find(&key)? + key.len();
+    let end = element[start..].find('"')?;
+    Some(element[start..start + end].to_string())
+}
+
+fn parse_msbuild_project(data: &str, manifest: &str) -> Vec<DeclaredDependency> {
+    let mut deps = Vec::new();
+    for element in data.split("<PackageReference").skip(1) {
+        let element = element.split('>').next().unwrap_or(element);
+        if let Some(name) = xml_attribute(element, "Include") {
+            let version = xml_attribute(element, "Version");
+            let kind = if element.contains("PrivateAssets=\"all\"") || element.contains("PrivateAssets=\"All\"") {
+                DependencyKind::Dev
+            } else {
+                DependencyKind::Runtime
+            };
+            deps.push(DeclaredDependency::new(&name, version, kind, "nuget", manifest));
+        }
+    }
+    for element in data.split("<ProjectReference").skip(1) {
+        let element = element.split('>').next().unwrap_or(element);
+        if let Some(include) = xml_attribute(element, "Include") {
+            let name = include
+                .rsplit(['/', '\\'])
+                .next()
+                .unwrap_or(&include)
+                .trim_end_matches(".csproj")
+                .trim_end_matches(".fsproj")
+                .to_string();
+            deps.push(
+                DeclaredDependency::new(&name, None, DependencyKind::Runtime, "nuget", manifest)
+                    .with_source(DependencySource::Path),
+            );
+        }
+    }
+    deps
+}
+
+#[tauri::command]
+pub fn get_project_dependencies(project_path: String) -> Result<Vec<DeclaredDependency>, String> {
+    let path = Path::new(&project_path);
+    if !path.is_dir() {
+        return Err(format!("Project directory not found: {}", project_path));
+    }
+    Ok(parse_manifests(path))
+}
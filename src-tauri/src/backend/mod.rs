@@ -1,43 +1,10 @@
-/// Tauri command to get the latest commit hash for a project directory
-#[tauri::command]
-pub fn get_latest_commit_hash(project_path: String) -> Result<String, String> {
-    use std::process::Command;
-    let output = Command::new("git")
-        .args(["rev-parse", "HEAD"])
-        .current_dir(&project_path)
-        .output()
-        .map_err(|e| format!("Failed to run git rev-parse: {}", e))?;
-    if !output.status.success() {
-        return Err(format!("git rev-parse failed: {}", String::from_utf8_lossy(&output.stderr)));
-    }
-    let hash = String::from_utf8_lossy(&output.stdout).trim().to_string();
-    Ok(hash)
-}
 use std::fs;
-use std::fs::File;
-use std::io::{Read, Write};
 use tauri::Manager;
 use tauri::Emitter;
-/// Get or create a persistent UUID for a project directory
+/// Get or create a persistent UUID for a project directory, stored in its `.project.toml`
 #[tauri::command]
 pub fn get_or_create_project_uuid(project_path: String) -> Result<String, String> {
-    let id_path = std::path::Path::new(&project_path).join(".project_id");
-    if id_path.exists() {
-        let mut s = String::new();
-        File::open(&id_path)
-            .map_err(|e| format!("Failed to open .project_id: {}", e))?
-            .read_to_string(&mut s)
-            .map_err(|e| format!("Failed to read .project_id: {}", e))?;
-        let s = s.trim();
-        if Uuid::parse_str(s).is_ok() {
-            return Ok(s.to_string());
-        }
-    }
-    let new_id = Uuid::new_v4().to_string();
-    File::create(&id_path)
-        .and_then(|mut f| f.write_all(new_id.as_bytes()))
-        .map_err(|e| format!("Failed to write .project_id: {}", e))?;
-    Ok(new_id)
+    project_meta::ensure_project_id(std::path::Path::new(&project_path))
 }
 /// Tauri command to trigger handle_git_commit_timeline from CLI/HTTP
 #[tauri::command]
@@ -60,7 +27,6 @@ pub async fn trigger_git_commit_timeline(
     ).await
 }
 use crate::backend::timeline_ai::generate_commit_summary;
-use std::process::Command;
 /// Call this after a git commit to generate and emit a timeline event using AI
 pub async fn handle_git_commit_timeline(
     app_handle: tauri::AppHandle,
@@ -75,12 +41,7 @@ pub async fn handle_git_commit_timeline(
     let project_summary = std::fs::read_to_string(&summary_path).unwrap_or_default();
 
     // 2. Get commit diff
-    let diff_output = Command::new("git")
-        .args(["diff-tree", "-p", commit_hash])
-        .current_dir(project_path)
-        .output()
-        .map_err(|e| format!("Failed to run git diff-tree: {}", e))?;
-    let commit_diff = String::from_utf8_lossy(&diff_output.stdout).to_string();
+    let commit_diff = git::commit_patch(std::path::Path::new(project_path), commit_hash)?;
 
     // 3. Call AI to generate summary
     let summary = generate_commit_summary(&project_summary, &commit_diff, openai_key)
@@ -98,7 +59,7 @@ pub async fn handle_git_commit_timeline(
             hash: commit_hash.to_string(),
             message: summary.clone(),
         },
-        title: format!("Commit {}", &commit_hash[..7]),
+        title: format!("Commit {}", &commit_hash[..7.min(commit_hash.len())]),
         description: Some(summary),
         metadata: Default::default(),
         user_id: None,
@@ -116,10 +77,37 @@ pub mod timeline_service;
 mod timeline_ai;
 mod kanban;
 pub mod kanban_service;
+pub mod project_index_service;
+pub mod project_scan;
+pub mod project_detect;
+pub mod languages;
+pub mod ignore_rules;
+pub mod git;
+pub mod git_history;
+pub mod workspace_members;
+pub mod manifests;
+pub mod templates;
+pub mod scaffold;
+pub mod repo_init;
+pub mod relocate;
+pub mod archive_service;
+pub mod archive;
+pub mod trash_service;
+pub mod trash;
+pub mod clone;
+pub mod project_meta;
+pub mod cleanup;
+pub mod commit_hook;
+pub mod branches;
+pub mod diff;
+pub mod watcher;
 use timeline::*;
 use timeline_service::TimelineService;
 use kanban::*;
 use kanban_service::KanbanService;
+use project_index_service::{project_fingerprint, ProjectIndexService};
+use project_scan::{build_category_tree, collect_scan_targets, scan_targets_parallel};
+use std::sync::atomic::AtomicBool;
 use uuid::Uuid;
 use chrono::{DateTime, Utc};
 // Timeline Tauri commands
@@ -254,16 +242,34 @@ use tauri::AppHandle;
 
 use crate::{file_ops, workspace};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Project {
     pub name: String,
     pub path: String,
     pub project_type: String,
+    /// Secondary frameworks and tooling detected alongside `project_type`
+    #[serde(default)]
+    pub framework_tags: Vec<String>,
     pub last_modified: String,
     pub size: u64,
     pub files_count: usize,
+    /// Files and line counts per language, largest first
+    #[serde(default)]
+    pub languages: Vec<languages::LanguageStats>,
+    /// Single-word summary of `git`, kept for the project cards
     pub git_status: String,
+    #[serde(default)]
+    pub git: git::GitSummary,
     pub starred: bool,
+    /// Packages of a Cargo, npm/yarn or pnpm workspace rooted at this project
+    #[serde(default)]
+    pub members: Vec<Project>,
+    /// Description, status, tags and the rest of `.project.toml`; `None` until the project has one
+    #[serde(default)]
+    pub metadata: Option<project_meta::ProjectMetadata>,
+    /// Why `.project.toml` couldn't be read; `metadata` is then `None`
+    #[serde(default)]
+    pub metadata_error: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -271,6 +277,9 @@ pub struct ProjectCategory {
     pub name: String,
     pub path: String,
     pub projects: Vec<Project>,
+    /// Nested groupings, e.g. `web-apps/clients`
+    #[serde(default)]
+    pub children: Vec<ProjectCategory>,
 }
 
 #[tauri::command]
@@ -279,55 +288,126 @@ pub fn initialize_workspace(app_handle: AppHandle) -> Result<String, String> {
     Ok(base.to_string_lossy().to_string())
 }
 
+/// Projects keyed by category path; nested groups appear as `category/group`
 #[tauri::command]
-pub fn scan_projects(base_dir: String) -> Result<HashMap<String, Vec<Project>>, String> {
-    let mut projects_map = HashMap::new();
-    let base_path = Path::new(&base_dir);
-    let starred = load_starred_projects().unwrap_or_default();
-
-    // Dynamically find all subdirectories (categories)
-    let categories = match fs::read_dir(base_path) {
-        Ok(entries) => entries
-            .filter_map(|entry| {
-                entry.ok().and_then(|e| {
-                    let path = e.path();
-                    if path.is_dir() {
-                        path.file_name().map(|n| n.to_string_lossy().to_string())
-                    } else {
-                        None
-                    }
-                })
-            })
-            .collect::<Vec<_>>(),
-        Err(_) => vec![],
-    };
-
-    for category in categories.iter() {
-        let category_path = base_path.join(category);
-        let mut projects = Vec::new();
+pub fn scan_projects(
+    app_handle: AppHandle,
+    base_dir: String,
+    index: State<'_, ProjectIndexService>,
+) -> Result<HashMap<String, Vec<Project>>, String> {
+    scan_workspace(&base_dir, workspace::discovery_depth(&app_handle), &index, false)
+}
 
-        if category_path.exists() && category_path.is_dir() {
-            let entries = fs::read_dir(&category_path)
-                .map_err(|e| format!("Failed to read directory {}: {}", category, e))?;
+/// Ignore the project index and re-scan every project from disk
+#[tauri::command]
+pub fn rescan_projects(
+    app_handle: AppHandle,
+    base_dir: String,
+    index: State<'_, ProjectIndexService>,
+) -> Result<HashMap<String, Vec<Project>>, String> {
+    scan_workspace(&base_dir, workspace::discovery_depth(&app_handle), &index, true)
+}
 
-            for entry in entries {
-                let entry = entry.map_err(|e| format!("Failed to read directory entry: {}", e))?;
-                let path = entry.path();
+/// Same as `scan_projects`, arranged as a category tree
+#[tauri::command]
+pub fn scan_project_tree(
+    app_handle: AppHandle,
+    base_dir: String,
+    index: State<'_, ProjectIndexService>,
+) -> Result<Vec<ProjectCategory>, String> {
+    let projects_map =
+        scan_workspace(&base_dir, workspace::discovery_depth(&app_handle), &index, false)?;
+    Ok(build_category_tree(Path::new(&base_dir), projects_map))
+}
 
-                if path.is_dir() {
-                    let project = scan_project_directory(&path, &starred)?;
-                    projects.push(project);
-                }
+#[tauri::command]
+pub fn set_discovery_depth(app_handle: AppHandle, depth: usize) -> Result<(), String> {
+    if depth == 0 {
+        return Err("Discovery depth must be at least 1".to_string());
+    }
+    let base_dir = workspace::ensure_workspace(&app_handle)?;
+    let mut cfg = workspace::WorkspaceConfig::load(&app_handle)
+        .unwrap_or_else(|| workspace::WorkspaceConfig::new(base_dir));
+    cfg.discovery_depth = depth;
+    cfg.save(&app_handle).map_err(|e| e.to_string())
+}
+
+fn scan_workspace(
+    base_dir: &str,
+    max_depth: usize,
+    index: &ProjectIndexService,
+    full: bool,
+) -> Result<HashMap<String, Vec<Project>>, String> {
+    let starred = load_starred_projects().unwrap_or_default();
+    let (categories, targets) = collect_scan_targets(Path::new(base_dir), max_depth)?;
+
+    let projects_map = Mutex::new(
+        categories
+            .into_iter()
+            .map(|category| (category, Vec::new()))
+            .collect::<HashMap<String, Vec<Project>>>(),
+    );
+    let first_error = Mutex::new(None);
+    let never_cancel = AtomicBool::new(false);
+
+    scan_targets_parallel(&targets, &starred, index, full, &never_cancel, |target, result| {
+        match result {
+            Ok(project) => projects_map
+                .lock()
+                .unwrap()
+                .entry(target.category.clone())
+                .or_default()
+                .push(project),
+            Err(e) => {
+                first_error.lock().unwrap().get_or_insert(e);
             }
         }
+    });
+
+    if let Some(e) = first_error.into_inner().unwrap() {
+        return Err(e);
+    }
 
-        projects_map.insert(category.to_string(), projects);
+    let seen = targets
+        .iter()
+        .map(|t| t.path.to_string_lossy().to_string())
+        .collect::<HashSet<_>>();
+    let _ = index.retain(base_dir, &seen);
+    Ok(projects_map.into_inner().unwrap())
+}
+
+/// Scan a project, reusing the indexed result when its fingerprint is unchanged
+pub(crate) fn scan_project_indexed(
+    path: &Path,
+    starred_set: &HashSet<String>,
+    index: &ProjectIndexService,
+    full: bool,
+) -> Result<Project, String> {
+    let key = path.to_string_lossy().to_string();
+    let fingerprint = project_fingerprint(path);
+
+    if !full {
+        if let Ok(Some(mut project)) = index.get(&key, &fingerprint) {
+            project.starred = starred_set.contains(&key);
+            return Ok(project);
+        }
     }
 
-    Ok(projects_map)
+    let project = scan_project_directory(path, starred_set)?;
+    let _ = index.upsert(&project, &fingerprint);
+    Ok(project)
 }
 
 fn scan_project_directory(path: &Path, starred_set: &HashSet<String>) -> Result<Project, String> {
+    let mut project = scan_single_project(path, starred_set)?;
+    project.members = workspace_members::member_paths(path)
+        .iter()
+        .filter_map(|member| scan_single_project(member, starred_set).ok())
+        .collect();
+    Ok(project)
+}
+
+fn scan_single_project(path: &Path, starred_set: &HashSet<String>) -> Result<Project, String> {
     let name = path
         .file_name()
         .ok_or("Invalid project directory name")?
@@ -341,91 +421,34 @@ fn scan_project_directory(path: &Path, starred_set: &HashSet<String>) -> Result<
         .modified()
         .map_err(|e| format!("Failed to get modification time: {}", e))?;
 
-    let project_type = detect_project_type(path);
-    let (size, files_count) = calculate_directory_stats(path)?;
-    let git_status = get_git_status(path);
+    let detection = project_detect::registry().detect(path);
+    let stats = ignore_rules::calculate_directory_stats(path, false)?;
+    let languages = languages::count_languages(path);
+    let git = git::git_summary(path);
     let starred = starred_set.contains(&path.to_string_lossy().to_string());
+    let (metadata, metadata_error) = match project_meta::read_metadata(path) {
+        Ok(metadata) => (metadata, None),
+        Err(e) => (None, Some(e)),
+    };
 
     Ok(Project {
         name,
         path: path.to_string_lossy().to_string(),
-        project_type,
+        project_type: detection.primary,
+        framework_tags: detection.tags,
         last_modified: format_system_time(last_modified),
-        size,
-        files_count,
-        git_status,
+        size: stats.size,
+        files_count: stats.files_count,
+        languages,
+        git_status: git.status_label(),
+        git,
         starred,
+        members: Vec::new(),
+        metadata,
+        metadata_error,
     })
 }
 
-fn detect_project_type(path: &Path) -> String {
-    if path.join("Cargo.toml").exists() {
-        if path.join("src-tauri").exists() {
-            return "tauri".to_string();
-        }
-        return "rust".to_string();
-    }
-
-    if path.join("package.json").exists() {
-        let package_json = fs::read_to_string(path.join("package.json")).unwrap_or_default();
-        if package_json.contains("\"next\"") {
-            return "next".to_string();
-        }
-        if package_json.contains("\"react\"") {
-            return "react".to_string();
-        }
-        if package_json.contains("\"electron\"") {
-            return "electron".to_string();
-        }
-        return "node".to_string();
-    }
-
-    if path.join("go.mod").exists() {
-        return "go".to_string();
-    }
-
-    if path.join("requirements.txt").exists() || path.join("pyproject.toml").exists() {
-        return "python".to_string();
-    }
-
-    if path.join("README.md").exists() {
-        return "markdown".to_string();
-    }
-
-    "unknown".to_string()
-}
-
-fn calculate_directory_stats(path: &Path) -> Result<(u64, usize), String> {
-    let mut total_size = 0;
-    let mut files_count = 0;
-
-    fn visit_dir(dir: &Path, size: &mut u64, count: &mut usize) -> std::io::Result<()> {
-        if dir.is_dir() {
-            for entry in fs::read_dir(dir)? {
-                let entry = entry?;
-                let path = entry.path();
-                if path.is_dir() {
-                    if let Some(name) = path.file_name() {
-                        if name == "node_modules" || name == "target" || name == ".git" {
-                            continue;
-                        }
-                    }
-                    visit_dir(&path, size, count)?;
-                } else {
-                    *size += entry.metadata()?.len();
-                    *count += 1;
-                }
-            }
-        }
-        Ok(())
-    }
-
-    visit_dir(path, &mut total_size, &mut files_count)
-        .map_err(|e| format!("Failed to calculate directory stats: {}", e))?;
-
-    Ok((total_size, files_count))
-}
-
 fn format_system_time(time: std::time::SystemTime) -> String {
     use chrono::{DateTime, Utc};
     use std::time::UNIX_EPOCH;
@@ -437,48 +460,6 @@ fn format_system_time(time: std::time::SystemTime) -> String {
     datetime.format("%Y-%m-%d %H:%M:%S").to_string()
 }
 
-fn get_git_status(path: &Path) -> String {
-    use std::process::Command;
-
-    if !path.join(".git").exists() {
-        return "clean".to_string();
-    }
-
-    let output = Command::new("git")
-        .args([
-            "-C",
-            path.to_str().unwrap_or(""),
-            "status",
-            "--porcelain",
-            "--branch",
-        ])
-        .output();
-
-    if let Ok(out) = output {
-        if out.status.success() {
-            let stdout = String::from_utf8_lossy(&out.stdout);
-            let mut state = "clean".to_string();
-            for (i, line) in stdout.lines().enumerate() {
-                if i == 0 && line.starts_with("##") {
-                    if line.contains("ahead") && line.contains("behind") {
-                        state = "diverged".into();
-                    } else if line.contains("ahead") {
-                        state = "ahead".into();
-                    } else if line.contains("behind") {
-                        state = "behind".into();
-                    }
-                } else if !line.trim().is_empty() {
-                    state = "modified".into();
-                    break;
-                }
-            }
-            return state;
-        }
-    }
-
-    "clean".to_string()
-}
-
 fn starred_file_path() -> Result<PathBuf, String> {
     let mut path = dirs::data_dir().ok_or("Could not find data directory")?;
     path.push("project-manager");
@@ -487,7 +468,7 @@ fn starred_file_path() -> Result<PathBuf, String> {
     Ok(path)
 }
 
-fn load_starred_projects() -> Result<HashSet<String>, String> {
+pub(crate) fn load_starred_projects() -> Result<HashSet<String>, String> {
     let file = starred_file_path()?;
     if let Ok(data) = fs::read_to_string(&file) {
         let list: HashSet<String> = serde_json::from_str(&data).unwrap_or_default();
@@ -504,6 +485,32 @@ fn save_starred_projects(set: &HashSet<String>) -> Result<(), String> {
     fs::write(file, data).map_err(|e| format!("Failed to write starred file: {}", e))
 }
 
+/// `path` moved from under `from` to under `to`, if it was under `from`
+pub(crate) fn rebase_path(path: &str, from: &Path, to: &Path) -> Option<String> {
+    Path::new(path)
+        .strip_prefix(from)
+        .ok()
+        .map(|rest| to.join(rest).to_string_lossy().to_string())
+}
+
+/// Re-key starred projects under `from` to `to`, or unstar them when `to` is `None`
+pub(crate) fn relocate_starred_projects(from: &Path, to: Option<&Path>) -> Result<(), String> {
+    let starred = load_starred_projects()?;
+    let updated = starred
+        .iter()
+        .filter_map(|path| {
+            if !Path::new(path).starts_with(from) {
+                return Some(path.clone());
+            }
+            to.and_then(|to| rebase_path(path, from, to))
+        })
+        .collect::<HashSet<_>>();
+    if updated != starred {
+        save_starred_projects(&updated)?;
+    }
+    Ok(())
+}
+
 #[tauri::command]
 pub fn toggle_project_star(project_path: String) -> Result<(), String> {
     let mut starred = load_starred_projects()?;
@@ -532,6 +539,9 @@ pub fn create_project(
     category: String,
     name: String,
     project_type: String,
+    template: Option<String>,
+    variables: Option<HashMap<String, String>>,
+    repository: Option<repo_init::RepositoryOptions>,
     app_handle: tauri::AppHandle,
 ) -> Result<String, String> {
     let base_path = Path::new(&base_dir);
@@ -545,42 +555,57 @@ pub fn create_project(
         ));
     }
 
+    // Fall back to the blank template for types without one of their own
+    let template = match template {
+        Some(id) => templates::load_template(&id)?,
+        None => templates::load_template(&project_type)
+            .or_else(|_| templates::load_template("blank"))?,
+    };
+    let mut provided = variables.unwrap_or_default();
+    provided
+        .entry("project_type".to_string())
+        .or_insert_with(|| project_type.clone());
+    let values = templates::resolve_variables(&template, &name, &provided)?;
+    let project_type = values
+        .get("project_type")
+        .cloned()
+        .unwrap_or(project_type);
+
+    let repository = repository.unwrap_or_default();
+    repo_init::check_options(&repository)?;
     fs::create_dir_all(&project_path)
         .map_err(|e| format!("Failed to create project directory: {}", e))?;
-
-    match project_type.as_str() {
-        "rust" => {
-            fs::write(project_path.join("Cargo.toml"), generate_cargo_toml(&name))
-                .map_err(|e| format!("Failed to create Cargo.toml: {}", e))?;
-            fs::create_dir_all(project_path.join("src"))
-                .map_err(|e| format!("Failed to create src directory: {}", e))?;
-            fs::write(
-                project_path.join("src/main.rs"),
-                "fn main() {\n    println!(\"Hello, world!\");\n}",
-            )
-            .map_err(|e| format!("Failed to create main.rs: {}", e))?;
-        }
-        "node" | "react" | "next" => {
-            fs::write(
-                project_path.join("package.json"),
-                generate_package_json(&name, &project_type),
-            )
-            .map_err(|e| format!("Failed to create package.json: {}", e))?;
-        }
-        _ => {
-            fs::write(
-                project_path.join("README.md"),
-                format!("# {}\n\nA new {} project.", name, project_type),
-            )
-            .map_err(|e| format!("Failed to create README.md: {}", e))?;
+    // Don't leave a half-written project behind
+    let initial_commit = match templates::instantiate(&template, &project_path, &values)
+        .and_then(|_| repo_init::write_repository_files(&project_path, &project_type, &repository))
+        .and_then(|_| repo_init::initialize_repository(&project_path, &repository).map_err(String::from))
+    {
+        Ok(hash) => hash,
+        Err(e) => {
+            let _ = fs::remove_dir_all(&project_path);
+            return Err(e);
         }
+    };
+
+    record_project_created(&app_handle, &project_path, &name, &category, &project_type);
+    if let Some(hash) = initial_commit {
+        repo_init::record_initial_commit(&app_handle, &project_path, &hash);
     }
 
-    // Emit timeline event for project creation
+    Ok(project_path.to_string_lossy().to_string())
+}
+
+/// Emit and store the ProjectCreated timeline event for a new project
+pub(crate) fn record_project_created(
+    app_handle: &AppHandle,
+    project_path: &Path,
+    name: &str,
+    category: &str,
+    project_type: &str,
+) {
     use crate::backend::timeline::{TimelineEvent, TimelineEventType};
     use uuid::Uuid;
     use chrono::Utc;
-    use std::collections::HashMap;
 
     // Get or create project UUID
     let project_id = match get_or_create_project_uuid(project_path.to_string_lossy().to_string()) {
@@ -597,7 +622,7 @@ pub fn create_project(
         description: Some(format!("Project '{}' was created in category '{}' as a {} project.", name, category, project_type)),
         metadata: HashMap::new(),
         user_id: None,
-        tags: vec!["created".to_string(), project_type.clone()],
+        tags: vec!["created".to_string(), project_type.to_string()],
     };
 
     // Try to emit and store the event
@@ -605,48 +630,6 @@ pub fn create_project(
     if let Some(timeline_service) = app_handle.try_state::<TimelineService>() {
         let _ = timeline_service.add_event(&event);
     }
-
-    Ok(project_path.to_string_lossy().to_string())
-}
-
-fn generate_cargo_toml(name: &str) -> String {
-    format!(
-        r#"[package]
-name = "{}"
-version = "0.1.0"
-edition = "2021"
-
-[dependencies]
-"#,
-        name
-    )
-}
-
-fn generate_package_json(name: &str, project_type: &str) -> String {
-    let dependencies = match project_type {
-        "react" => {
-            r#"    "react": "^18.2.0",
-    "react-dom": "^18.2.0""#
-        }
-        "next" => {
-            r#"    "next": "^14.0.0",
-    "react": "^18.2.0",
-    "react-dom": "^18.2.0""#
-        }
-        _ => r#"    "express": "^4.18.0""#,
-    };
-
-    format!(
-        r#"{{
-  "name": "{}",
-  "version": "1.0.0",
-  "description": "",
-  "dependencies": {{
-{}
-  }}
-}}"#,
-        name, dependencies
-    )
 }
 
 #[tauri::command]
@@ -714,58 +697,64 @@ pub fn open_project_in_file_manager(project_path: String) -> Result<(), String>
 pub fn get_project_structure(project_path: String) -> Result<serde_json::Value, String> {
     use serde_json::json;
 
-    fn build_tree(
-        path: &Path,
-        max_depth: usize,
-        current_depth: usize,
-    ) -> Result<serde_json::Value, String> {
-        if current_depth >= max_depth {
-            return Ok(json!({}));
-        }
+    let path = Path::new(&project_path);
+    if !path.is_dir() {
+        return Err(format!("Failed to read directory: {} not found", project_path));
+    }
 
-        let mut tree = serde_json::Map::new();
-        let mut entries: Vec<_> = fs::read_dir(path)
-            .map_err(|e| format!("Failed to read directory: {}", e))?
-            .filter_map(|e| e.ok())
-            .collect();
-
-        entries.sort_by(|a, b| {
-            let a_path = a.path();
-            let b_path = b.path();
-            let a_is_dir = a_path.is_dir();
-            let b_is_dir = b_path.is_dir();
+    let mut walker = ignore_rules::project_walker(path);
+    walker
+        .hidden(true)
+        .max_depth(Some(3))
+        .sort_by_file_path(|a, b| {
+            let a_is_dir = a.is_dir();
+            let b_is_dir = b.is_dir();
             if a_is_dir && !b_is_dir {
                 std::cmp::Ordering::Less
             } else if !a_is_dir && b_is_dir {
                 std::cmp::Ordering::Greater
             } else {
                 a.file_name()
+                    .unwrap_or_default()
                     .to_string_lossy()
                     .to_lowercase()
-                    .cmp(&b.file_name().to_string_lossy().to_lowercase())
+                    .cmp(&b.file_name().unwrap_or_default().to_string_lossy().to_lowercase())
             }
         });
 
-        for entry in entries {
-            let path = entry.path();
-            let name = path.file_name().unwrap().to_string_lossy().to_string();
-
-            if name.starts_with('.') || name == "node_modules" || name == "target" {
-                continue;
+    // The walker yields directories before their contents
+    fn insert_entry(
+        node: &mut serde_json::Map<String, serde_json::Value>,
+        components: &[String],
+        value: serde_json::Value,
+    ) {
+        match components {
+            [name] => {
+                node.insert(name.clone(), value);
             }
-
-            if path.is_dir() {
-                tree.insert(name, build_tree(&path, max_depth, current_depth + 1)?);
-            } else {
-                tree.insert(name, json!("file"));
+            [parent, rest @ ..] => {
+                if let Some(child) = node.get_mut(parent).and_then(|v| v.as_object_mut()) {
+                    insert_entry(child, rest, value);
+                }
             }
+            [] => {}
         }
+    }
 
-        Ok(json!(tree))
+    let mut tree = serde_json::Map::new();
+    for entry in walker.build().filter_map(|e| e.ok()) {
+        let Ok(relative) = entry.path().strip_prefix(path) else {
+            continue;
+        };
+        let components = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().to_string())
+            .collect::<Vec<_>>();
+        let is_dir = entry.file_type().map(|t| t.is_dir()).unwrap_or(false);
+        insert_entry(&mut tree, &components, if is_dir { json!({}) } else { json!("file") });
     }
 
-    let path = Path::new(&project_path);
-    build_tree(path, 3, 0)
+    Ok(json!(tree))
 }
 
 #[tauri::command]
@@ -0,0 +1,201 @@
+use crate::backend::manifests;
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock, RwLock};
+
+const BUILTIN_RULES: &str = include_str!("project_types.toml");
+
+/// A single project type or framework detection rule
+#[derive(Debug, Clone, Hash, Serialize, Deserialize)]
+pub struct DetectorRule {
+    pub name: String,
+    #[serde(default)]
+    pub priority: i32,
+    /// Relative paths or file-name globs; any one must exist
+    #[serde(default)]
+    pub markers: Vec<String>,
+    /// Dependency names; any one must be declared by a root manifest
+    #[serde(default)]
+    pub dependencies: Vec<String>,
+    /// Only ever reported as a tag, never as the primary type
+    #[serde(default)]
+    pub tag_only: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct RuleFile {
+    #[serde(default)]
+    rule: Vec<DetectorRule>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProjectDetection {
+    pub primary: String,
+    pub tags: Vec<String>,
+}
+
+pub struct ProjectTypeRegistry {
+    rules: Vec<DetectorRule>,
+    fingerprint: u64,
+}
+
+impl ProjectTypeRegistry {
+    /// Built-in rules overlaid with the user's rule file, if present
+    pub fn load() -> Result<Self, String> {
+        let mut rules = parse_rules(BUILTIN_RULES)?;
+        if let Ok(path) = user_rules_path() {
+            if let Ok(data) = fs::read_to_string(&path) {
+                for rule in parse_rules(&data)? {
+                    rules.retain(|r| r.name != rule.name);
+                    rules.push(rule);
+                }
+            }
+        }
+        rules.sort_by(|a, b| b.priority.cmp(&a.priority));
+        Ok(Self::new(rules))
+    }
+
+    fn new(rules: Vec<DetectorRule>) -> Self {
+        let mut hasher = DefaultHasher::new();
+        rules.hash(&mut hasher);
+        Self {
+            rules,
+            fingerprint: hasher.finish(),
+        }
+    }
+
+    pub fn rules(&self) -> &[DetectorRule] {
+        &self.rules
+    }
+
+    /// Hash of the active rules, so cached detections are redone when they change
+    pub fn fingerprint(&self) -> u64 {
+        self.fingerprint
+    }
+
+    pub fn detect(&self, path: &Path) -> ProjectDetection {
+        let dependencies = declared_dependency_names(path);
+        let matched = self
+            .rules
+            .iter()
+            .filter(|rule| rule_matches(rule, path, &dependencies))
+            .collect::<Vec<_>>();
+
+        let primary = matched
+            .iter()
+            .find(|rule| !rule.tag_only)
+            .map(|rule| rule.name.clone())
+            .unwrap_or_else(|| "unknown".to_string());
+        let mut tags = Vec::new();
+        for rule in matched {
+            if rule.name != primary && !tags.contains(&rule.name) {
+                tags.push(rule.name.clone());
+            }
+        }
+
+        ProjectDetection { primary, tags }
+    }
+}
+
+fn parse_rules(data: &str) -> Result<Vec<DetectorRule>, String> {
+    toml::from_str::<RuleFile>(data)
+        .map(|file| file.rule)
+        .map_err(|e| format!("Failed to parse project type rules: {}", e))
+}
+
+fn user_rules_path() -> Result<PathBuf, String> {
+    let mut path = dirs::data_dir().ok_or("Could not find data directory")?;
+    path.push("project-manager");
+    path.push("project_types.toml");
+    Ok(path)
+}
+
+fn registry_cell() -> &'static RwLock<Arc<ProjectTypeRegistry>> {
+    static REGISTRY: OnceLock<RwLock<Arc<ProjectTypeRegistry>>> = OnceLock::new();
+    REGISTRY.get_or_init(|| {
+        // A broken user file falls back to the built-ins; reload reports the error
+        let registry = ProjectTypeRegistry::load()
+            .unwrap_or_else(|_| ProjectTypeRegistry::new(parse_rules(BUILTIN_RULES).unwrap_or_default()));
+        RwLock::new(Arc::new(registry))
+    })
+}
+
+/// The active detector registry
+pub fn registry() -> Arc<ProjectTypeRegistry> {
+    registry_cell().read().unwrap().clone()
+}
+
+fn rule_matches(rule: &DetectorRule, root: &Path, dependencies: &HashSet<String>) -> bool {
+    if rule.markers.is_empty() && rule.dependencies.is_empty() {
+        return false;
+    }
+    let markers_ok = rule.markers.is_empty() || rule.markers.iter().any(|m| marker_exists(root, m));
+    let deps_ok = rule.dependencies.is_empty()
+        || rule.dependencies.iter().any(|d| dependencies.contains(d));
+    markers_ok && deps_ok
+}
+
+fn marker_exists(root: &Path, marker: &str) -> bool {
+    if !marker.contains(['*', '?']) {
+        return root.join(marker).exists();
+    }
+    let (dir, pattern) = match marker.rsplit_once('/') {
+        Some((dir, pattern)) => (root.join(dir), pattern),
+        None => (root.to_path_buf(), marker),
+    };
+    fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .any(|e| wildcard_match(pattern, &e.file_name().to_string_lossy()))
+        })
+        .unwrap_or(false)
+}
+
+/// Match a single path segment against a pattern using `*` and `?`
+pub(crate) fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let name = name.chars().collect::<Vec<_>>();
+    let (mut p, mut n) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, n));
+            p += 1;
+        } else if let Some((star_p, star_n)) = star {
+            p = star_p + 1;
+            n = star_n + 1;
+            star = Some((star_p, star_n + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+/// Dependency names declared by the manifests in the project root
+fn declared_dependency_names(root: &Path) -> HashSet<String> {
+    manifests::parse_manifests(root)
+        .into_iter()
+        .filter(|dep| !dep.manifest.contains('/'))
+        .map(|dep| dep.name)
+        .collect()
+}
+
+/// Reload the built-in and user detection rules and return the active set
+#[tauri::command]
+pub fn reload_project_type_rules() -> Result<Vec<DetectorRule>, String> {
+    let registry = ProjectTypeRegistry::load()?;
+    let rules = registry.rules().to_vec();
+    *registry_cell()
+        .write()
+        .map_err(|_| "Failed to lock project type registry".to_string())? = Arc::new(registry);
+    Ok(rules)
+}
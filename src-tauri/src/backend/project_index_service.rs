@@ -0,0 +1,180 @@
+use crate::backend::git::changed_entries;
+use crate::backend::ignore_rules::global_ignore_path;
+use crate::backend::project_detect;
+use crate::backend::Project;
+use rusqlite::{params, Connection, OptionalExtension, Result as SqlResult};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::UNIX_EPOCH;
+use chrono::Utc;
+
+/// Bumped whenever the shape of a scanned `Project` changes so stale rows are re-scanned
+const INDEX_VERSION: u32 = 10;
+/// Git files whose mtime changes on commits, checkouts, staging and fetches.
+const GIT_FINGERPRINT_FILES: [&str; 5] = ["HEAD", "index", "FETCH_HEAD", "ORIG_HEAD", "logs/HEAD"];
+
+/// Persistent cache of scanned projects keyed by absolute project path.
+pub struct ProjectIndexService {
+    db: Arc<Mutex<Connection>>,
+}
+
+impl ProjectIndexService {
+    pub fn new(db_path: &str) -> Self {
+        let conn = Connection::open(db_path).expect("Failed to open project index DB");
+        conn.execute_batch(r#"
+            CREATE TABLE IF NOT EXISTS project_index (
+                path TEXT PRIMARY KEY,
+                fingerprint TEXT NOT NULL,
+                project TEXT NOT NULL,
+                indexed_at TEXT NOT NULL
+            );
+        "#).unwrap();
+        Self { db: Arc::new(Mutex::new(conn)) }
+    }
+
+    /// Returns the cached project if it was indexed with the same fingerprint.
+    pub fn get(&self, path: &str, fingerprint: &str) -> SqlResult<Option<Project>> {
+        let db = self.db.lock().unwrap();
+        let row: Option<(String, String)> = db
+            .query_row(
+                "SELECT fingerprint, project FROM project_index WHERE path = ?1",
+                params![path],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?;
+        Ok(row
+            .filter(|(stored, _)| stored == fingerprint)
+            .and_then(|(_, project)| serde_json::from_str(&project).ok()))
+    }
+
+    pub fn upsert(&self, project: &Project, fingerprint: &str) -> SqlResult<()> {
+        let db = self.db.lock().unwrap();
+        db.execute(
+            "INSERT INTO project_index (path, fingerprint, project, indexed_at)
+            VALUES (?1, ?2, ?3, ?4)
+            ON CONFLICT(path) DO UPDATE SET fingerprint = ?2, project = ?3, indexed_at = ?4",
+            params![
+                project.path,
+                fingerprint,
+                serde_json::to_string(project).unwrap(),
+                Utc::now().to_rfc3339(),
+            ]
+        )?;
+        Ok(())
+    }
+
+    /// Drops the rows for a project and anything nested under it, e.g. after a move.
+    pub fn remove_under(&self, path: &str) -> SqlResult<usize> {
+        let db = self.db.lock().unwrap();
+        let mut stmt = db.prepare("SELECT path FROM project_index")?;
+        let stale = stmt
+            .query_map([], |row| row.get::<_, String>(0))?
+            .filter_map(|p| p.ok())
+            .filter(|p| Path::new(p).starts_with(path))
+            .collect::<Vec<_>>();
+        for stale_path in &stale {
+            db.execute("DELETE FROM project_index WHERE path = ?1", params![stale_path])?;
+        }
+        Ok(stale.len())
+    }
+
+    /// Drops index rows under `base_dir` whose path was not seen by the latest scan.
+    pub fn retain(&self, base_dir: &str, seen: &HashSet<String>) -> SqlResult<usize> {
+        let db = self.db.lock().unwrap();
+        let mut stmt = db.prepare("SELECT path FROM project_index")?;
+        let stale = stmt
+            .query_map([], |row| row.get::<_, String>(0))?
+            .filter_map(|p| p.ok())
+            .filter(|p| Path::new(p).starts_with(base_dir) && !seen.contains(p))
+            .collect::<Vec<_>>();
+        for path in &stale {
+            db.execute("DELETE FROM project_index WHERE path = ?1", params![path])?;
+        }
+        Ok(stale.len())
+    }
+}
+
+/// Cheap change detector for a project directory.
+///
+/// Hashes the mtimes of every directory in the project (which change when
+/// entries are created, deleted or atomically saved), of the files directly in
+/// the project root (manifests and ignore files), of the app-level ignore list,
+/// of the git bookkeeping files and of every file `git status` reports as
+/// changed, so in-place edits inside a repository invalidate the entry. The
+/// active project type rules are hashed too. Deep, in-place edits outside a
+/// repository are not observed; `rescan_projects` covers those.
+pub fn project_fingerprint(path: &Path) -> String {
+    fn mtime_nanos(path: &Path) -> u128 {
+        fs::metadata(path)
+            .and_then(|m| m.modified())
+            .ok()
+            .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+            .map(|d| d.as_nanos())
+            .unwrap_or(0)
+    }
+
+    fn visit_dir(dir: &Path, root: &Path, hasher: &mut DefaultHasher) {
+        dir.strip_prefix(root).unwrap_or(dir).hash(hasher);
+        mtime_nanos(dir).hash(hasher);
+        let Ok(entries) = fs::read_dir(dir) else {
+            return;
+        };
+        let mut children = entries
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().map(|t| t.is_dir()).unwrap_or(false))
+            .map(|e| e.path())
+            .filter(|p| {
+                !matches!(
+                    p.file_name().and_then(|n| n.to_str()),
+                    Some("node_modules" | "target" | ".git")
+                )
+            })
+            .collect::<Vec<_>>();
+        children.sort();
+        for child in children {
+            visit_dir(&child, root, hasher);
+        }
+    }
+
+    let mut hasher = DefaultHasher::new();
+    INDEX_VERSION.hash(&mut hasher);
+    visit_dir(path, path, &mut hasher);
+
+    if let Ok(entries) = fs::read_dir(path) {
+        let mut files = entries
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().map(|t| t.is_file()).unwrap_or(false))
+            .map(|e| e.path())
+            .collect::<Vec<_>>();
+        files.sort();
+        for file in files {
+            file.file_name().hash(&mut hasher);
+            mtime_nanos(&file).hash(&mut hasher);
+        }
+    }
+
+    // Editing the app-level ignore list changes every project's stats
+    if let Ok(global) = global_ignore_path() {
+        mtime_nanos(&global).hash(&mut hasher);
+    }
+    // Reloaded or edited detection rules change every project's type
+    project_detect::registry().fingerprint().hash(&mut hasher);
+
+    let git_dir = path.join(".git");
+    for name in GIT_FINGERPRINT_FILES {
+        mtime_nanos(&git_dir.join(name)).hash(&mut hasher);
+    }
+    if git_dir.exists() {
+        for (relative, status) in changed_entries(path) {
+            relative.hash(&mut hasher);
+            status.hash(&mut hasher);
+            mtime_nanos(&path.join(&relative)).hash(&mut hasher);
+        }
+    }
+
+    format!("{:016x}", hasher.finish())
+}
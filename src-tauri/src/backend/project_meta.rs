@@ -0,0 +1,291 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use uuid::Uuid;
+
+/// Per-project metadata, kept in the project root
+pub const METADATA_FILE: &str = ".project.toml";
+/// The bare-UUID file that `.project.toml` replaces
+pub const LEGACY_ID_FILE: &str = ".project_id";
+
+const MAX_DESCRIPTION_LEN: usize = 2000;
+const MAX_TAG_LEN: usize = 40;
+const MAX_TAGS: usize = 32;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectStatus {
+    Idea,
+    #[default]
+    Active,
+    Paused,
+    Maintenance,
+    Completed,
+    Abandoned,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProjectLink {
+    pub label: String,
+    pub url: String,
+}
+
+/// Contents of `.project.toml`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProjectMetadata {
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub status: ProjectStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub links: Vec<ProjectLink>,
+    /// Free-form fields; values are strings, numbers, booleans or arrays of those
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub custom: BTreeMap<String, Value>,
+}
+
+/// Fields to change; `None` leaves a field as it is, an empty string clears `description` or `owner`
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ProjectMetadataUpdate {
+    pub description: Option<String>,
+    pub status: Option<ProjectStatus>,
+    pub owner: Option<String>,
+    pub tags: Option<Vec<String>>,
+    pub links: Option<Vec<ProjectLink>>,
+    pub custom: Option<BTreeMap<String, Value>>,
+}
+
+fn is_scalar(value: &Value) -> bool {
+    matches!(value, Value::String(_) | Value::Number(_) | Value::Bool(_))
+}
+
+fn is_valid_url(url: &str) -> bool {
+    let Some((scheme, rest)) = url.split_once(':') else {
+        return false;
+    };
+    !rest.is_empty()
+        && !scheme.is_empty()
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+        && !url.chars().any(char::is_whitespace)
+}
+
+impl ProjectMetadata {
+    pub fn new() -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Check the fields against the schema; every problem is reported, joined by `; `
+    pub fn validate(&self) -> Result<(), String> {
+        let mut errors = Vec::new();
+        if Uuid::parse_str(&self.id).is_err() {
+            errors.push(format!("id '{}' is not a UUID", self.id));
+        }
+        if let Some(description) = &self.description {
+            if description.chars().count() > MAX_DESCRIPTION_LEN {
+                errors.push(format!("description is longer than {} characters", MAX_DESCRIPTION_LEN));
+            }
+        }
+        if let Some(owner) = &self.owner {
+            if owner.trim().is_empty() || owner.contains('\n') {
+                errors.push("owner must be a single non-empty line".to_string());
+            }
+        }
+
+        if self.tags.len() > MAX_TAGS {
+            errors.push(format!("at most {} tags are allowed", MAX_TAGS));
+        }
+        let mut seen = HashSet::new();
+        for tag in &self.tags {
+            if tag.trim().is_empty() || tag.chars().count() > MAX_TAG_LEN || tag.contains([',', '\n']) {
+                errors.push(format!(
+                    "tag '{}' must be 1-{} characters without commas or line breaks",
+                    tag, MAX_TAG_LEN
+                ));
+            } else if !seen.insert(tag.to_lowercase()) {
+                errors.push(format!("tag '{}' is listed twice", tag));
+            }
+        }
+
+        for link in &self.links {
+            if link.label.trim().is_empty() {
+                errors.push(format!("link {} needs a label", link.url));
+            }
+            if !is_valid_url(&link.url) {
+                errors.push(format!("link '{}' is not a valid URL", link.url));
+            }
+        }
+
+        for (key, value) in &self.custom {
+            if key.is_empty()
+                || !key
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-'))
+            {
+                errors.push(format!(
+                    "custom field '{}' must use letters, digits, '_' or '-'",
+                    key
+                ));
+            }
+            let valid = match value {
+                Value::Array(items) => items.iter().all(is_scalar),
+                other => is_scalar(other),
+            };
+            if !valid {
+                errors.push(format!(
+                    "custom field '{}' must be a string, number, boolean or a list of those",
+                    key
+                ));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("Invalid project metadata: {}", errors.join("; ")))
+        }
+    }
+
+    fn apply(&mut self, update: ProjectMetadataUpdate) {
+        fn non_empty(value: String) -> Option<String> {
+            let value = value.trim().to_string();
+            (!value.is_empty()).then_some(value)
+        }
+        if let Some(description) = update.description {
+            self.description = non_empty(description);
+        }
+        if let Some(status) = update.status {
+            self.status = status;
+        }
+        if let Some(owner) = update.owner {
+            self.owner = non_empty(owner);
+        }
+        if let Some(tags) = update.tags {
+            self.tags = tags.into_iter().map(|t| t.trim().to_string()).collect();
+        }
+        if let Some(links) = update.links {
+            self.links = links
+                .into_iter()
+                .map(|l| ProjectLink {
+                    label: l.label.trim().to_string(),
+                    url: l.url.trim().to_string(),
+                })
+                .collect();
+        }
+        if let Some(custom) = update.custom {
+            self.custom = custom;
+        }
+    }
+}
+
+fn metadata_path(project_path: &Path) -> PathBuf {
+    project_path.join(METADATA_FILE)
+}
+
+fn write_metadata(project_path: &Path, metadata: &ProjectMetadata) -> Result<(), String> {
+    let data = toml::to_string_pretty(metadata)
+        .map_err(|e| format!("Failed to serialize {}: {}", METADATA_FILE, e))?;
+    fs::write(metadata_path(project_path), data)
+        .map_err(|e| format!("Failed to write {}: {}", METADATA_FILE, e))
+}
+
+/// Read `.project.toml`, first migrating a legacy `.project_id` into it.
+///
+/// Returns `None` for a project that has neither file yet.
+pub fn read_metadata(project_path: &Path) -> Result<Option<ProjectMetadata>, String> {
+    let path = metadata_path(project_path);
+    if path.exists() {
+        let data = fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read {}: {}", METADATA_FILE, e))?;
+        let metadata: ProjectMetadata = toml::from_str(&data)
+            .map_err(|e| format!("Failed to parse {}: {}", METADATA_FILE, e))?;
+        if Uuid::parse_str(&metadata.id).is_err() {
+            return Err(format!("{} has an invalid id '{}'", METADATA_FILE, metadata.id));
+        }
+        return Ok(Some(metadata));
+    }
+
+    let legacy = project_path.join(LEGACY_ID_FILE);
+    let Ok(id) = fs::read_to_string(&legacy) else {
+        return Ok(None);
+    };
+    let metadata = match Uuid::parse_str(id.trim()) {
+        Ok(id) => ProjectMetadata {
+            id: id.to_string(),
+            ..Default::default()
+        },
+        Err(_) => ProjectMetadata::new(),
+    };
+    write_metadata(project_path, &metadata)?;
+    let _ = fs::remove_file(&legacy);
+    Ok(Some(metadata))
+}
+
+/// Read a project's metadata, creating `.project.toml` with a fresh id when there is none
+pub fn ensure_metadata(project_path: &Path) -> Result<ProjectMetadata, String> {
+    if let Some(metadata) = read_metadata(project_path)? {
+        return Ok(metadata);
+    }
+    let metadata = ProjectMetadata::new();
+    write_metadata(project_path, &metadata)?;
+    Ok(metadata)
+}
+
+/// Read just the project's id, creating `.project.toml` when there is none.
+///
+/// Unlike `read_metadata` this doesn't validate the other fields, so a hand-edited file with an
+/// unknown key or a bad tag still yields the id and is left as it is.
+pub fn ensure_project_id(project_path: &Path) -> Result<String, String> {
+    let path = metadata_path(project_path);
+    if !path.exists() {
+        return ensure_metadata(project_path).map(|metadata| metadata.id);
+    }
+    let data = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read {}: {}", METADATA_FILE, e))?;
+    let table: toml::Table = toml::from_str(&data)
+        .map_err(|e| format!("Failed to parse {}: {}", METADATA_FILE, e))?;
+    let id = table
+        .get("id")
+        .and_then(toml::Value::as_str)
+        .ok_or_else(|| format!("{} has no id", METADATA_FILE))?;
+    Uuid::parse_str(id).map_err(|_| format!("{} has an invalid id '{}'", METADATA_FILE, id))?;
+    Ok(id.to_string())
+}
+
+#[tauri::command]
+pub fn get_project_metadata(project_path: String) -> Result<ProjectMetadata, String> {
+    let path = Path::new(&project_path);
+    if !path.is_dir() {
+        return Err(format!("Project directory not found: {}", project_path));
+    }
+    ensure_metadata(path)
+}
+
+/// Validate and save changes to a project's metadata; the id can't be changed
+#[tauri::command]
+pub fn update_project_metadata(
+    project_path: String,
+    update: ProjectMetadataUpdate,
+) -> Result<ProjectMetadata, String> {
+    let path = Path::new(&project_path);
+    if !path.is_dir() {
+        return Err(format!("Project directory not found: {}", project_path));
+    }
+    let mut metadata = ensure_metadata(path)?;
+    metadata.apply(update);
+    metadata.validate()?;
+    write_metadata(path, &metadata)?;
+    Ok(metadata)
+}
@@ -0,0 +1,416 @@
+use crate::backend::project_index_service::ProjectIndexService;
+use crate::backend::{load_starred_projects, scan_project_indexed, Project, ProjectCategory};
+use crate::workspace;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use tauri::{AppHandle, Emitter, Manager, State};
+use uuid::Uuid;
+
+/// A project directory queued for scanning
+#[derive(Debug, Clone)]
+pub struct ScanTarget {
+    pub category: String,
+    pub path: PathBuf,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ScanProjectResult {
+    pub scan_id: String,
+    pub category: String,
+    pub project: Project,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ScanProgress {
+    pub scan_id: String,
+    pub completed: usize,
+    pub total: usize,
+    pub failed: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ScanFinished {
+    pub scan_id: String,
+    pub completed: usize,
+    pub total: usize,
+    pub cancelled: bool,
+    pub categories: Vec<String>,
+    pub errors: Vec<String>,
+}
+
+/// Cancellation flags for in-flight scans, keyed by scan id
+#[derive(Default)]
+pub struct ScanRegistry {
+    scans: Mutex<HashMap<String, Arc<AtomicBool>>>,
+}
+
+impl ScanRegistry {
+    fn register(&self, scan_id: &str) -> Arc<AtomicBool> {
+        let flag = Arc::new(AtomicBool::new(false));
+        self.scans
+            .lock()
+            .unwrap()
+            .insert(scan_id.to_string(), flag.clone());
+        flag
+    }
+
+    fn finish(&self, scan_id: &str) {
+        self.scans.lock().unwrap().remove(scan_id);
+    }
+
+    fn cancel(&self, scan_id: &str) -> bool {
+        match self.scans.lock().unwrap().get(scan_id) {
+            Some(flag) => {
+                flag.store(true, Ordering::SeqCst);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Files or directories whose presence makes a directory a project
+const PROJECT_MARKERS: &[&str] = &[
+    ".git",
+    ".project.toml",
+    ".project_id",
+    "Cargo.toml",
+    "package.json",
+    "deno.json",
+    "go.mod",
+    "pyproject.toml",
+    "requirements.txt",
+    "setup.py",
+    "pom.xml",
+    "build.gradle",
+    "build.gradle.kts",
+    "composer.json",
+    "Gemfile",
+    "pubspec.yaml",
+];
+const PROJECT_MARKER_EXTENSIONS: &[&str] = &["csproj", "fsproj", "sln"];
+
+pub fn is_project_dir(path: &Path) -> bool {
+    if PROJECT_MARKERS.iter().any(|m| path.join(m).exists()) {
+        return true;
+    }
+    fs::read_dir(path)
+        .map(|entries| {
+            entries.filter_map(|e| e.ok()).any(|e| {
+                e.path()
+                    .extension()
+                    .map(|ext| PROJECT_MARKER_EXTENSIONS.iter().any(|m| ext == *m))
+                    .unwrap_or(false)
+            })
+        })
+        .unwrap_or(false)
+}
+
+enum DiscoveredDir {
+    Project { path: PathBuf },
+    Group { path: PathBuf, children: Vec<DiscoveredDir> },
+}
+
+fn sub_directories(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let entries = fs::read_dir(dir)
+        .map_err(|e| format!("Failed to read directory {}: {}", dir.display(), e))?;
+    let mut dirs = Vec::new();
+    for entry in entries {
+        let entry = entry.map_err(|e| format!("Failed to read directory entry: {}", e))?;
+        let path = entry.path();
+        if path.is_dir() {
+            dirs.push(path);
+        }
+    }
+    dirs.sort();
+    Ok(dirs)
+}
+
+/// Decide whether `dir` is a project or a grouping of projects.
+///
+/// Directories with a marker file are projects. Otherwise a directory is a
+/// group when something below it (within `max_depth` levels of the category)
+/// is a marked project; a marker-less directory with no such descendants is
+/// still treated as a project, as plain folders always have been.
+fn classify_dir(dir: &Path, level: usize, max_depth: usize) -> DiscoveredDir {
+    if is_project_dir(dir) || level >= max_depth {
+        return DiscoveredDir::Project { path: dir.to_path_buf() };
+    }
+
+    let children = sub_directories(dir)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|p| {
+            !matches!(
+                p.file_name().and_then(|n| n.to_str()),
+                Some(name) if name.starts_with('.') || name == "node_modules" || name == "target"
+            )
+        })
+        .map(|p| classify_dir(&p, level + 1, max_depth))
+        .collect::<Vec<_>>();
+
+    let has_marked_project = children.iter().any(|child| match child {
+        DiscoveredDir::Group { .. } => true,
+        DiscoveredDir::Project { path } => is_project_dir(path),
+    });
+    if has_marked_project {
+        DiscoveredDir::Group { path: dir.to_path_buf(), children }
+    } else {
+        DiscoveredDir::Project { path: dir.to_path_buf() }
+    }
+}
+
+fn flatten_discovered(
+    node: DiscoveredDir,
+    category: &str,
+    categories: &mut Vec<String>,
+    targets: &mut Vec<ScanTarget>,
+) {
+    match node {
+        DiscoveredDir::Project { path } => targets.push(ScanTarget {
+            category: category.to_string(),
+            path,
+        }),
+        DiscoveredDir::Group { path, children } => {
+            let name = path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let group = format!("{}/{}", category, name);
+            categories.push(group.clone());
+            for child in children {
+                flatten_discovered(child, &group, categories, targets);
+            }
+        }
+    }
+}
+
+/// List every category (nested groups as `category/group` paths) and the projects inside it.
+///
+/// `max_depth` bounds how many levels below a top-level category are searched;
+/// a depth of 1 treats every direct child of a category as a project.
+pub fn collect_scan_targets(
+    base_path: &Path,
+    max_depth: usize,
+) -> Result<(Vec<String>, Vec<ScanTarget>), String> {
+    let top_level = match fs::read_dir(base_path) {
+        Ok(entries) => entries
+            .filter_map(|entry| {
+                entry.ok().and_then(|e| {
+                    let path = e.path();
+                    if path.is_dir() {
+                        path.file_name().map(|n| n.to_string_lossy().to_string())
+                    } else {
+                        None
+                    }
+                })
+            })
+            .collect::<Vec<_>>(),
+        Err(_) => vec![],
+    };
+
+    let mut categories = Vec::new();
+    let mut targets = Vec::new();
+    for category in top_level {
+        categories.push(category.clone());
+        for child in sub_directories(&base_path.join(&category))? {
+            let node = classify_dir(&child, 1, max_depth.max(1));
+            flatten_discovered(node, &category, &mut categories, &mut targets);
+        }
+    }
+
+    Ok((categories, targets))
+}
+
+/// Arrange flat category paths and their projects into a tree
+pub fn build_category_tree(
+    base_path: &Path,
+    mut projects_map: HashMap<String, Vec<Project>>,
+) -> Vec<ProjectCategory> {
+    fn take_children(
+        parent: &str,
+        base_path: &Path,
+        projects_map: &mut HashMap<String, Vec<Project>>,
+        paths: &[String],
+    ) -> Vec<ProjectCategory> {
+        paths
+            .iter()
+            .filter(|p| match p.rsplit_once('/') {
+                Some((prefix, _)) => prefix == parent,
+                None => parent.is_empty(),
+            })
+            .map(|p| ProjectCategory {
+                name: p.rsplit('/').next().unwrap_or(p).to_string(),
+                path: base_path.join(p).to_string_lossy().to_string(),
+                projects: projects_map.remove(p).unwrap_or_default(),
+                children: take_children(p, base_path, projects_map, paths),
+            })
+            .collect()
+    }
+
+    let mut paths = projects_map.keys().cloned().collect::<Vec<_>>();
+    paths.sort();
+    take_children("", base_path, &mut projects_map, &paths)
+}
+
+/// Scan targets on a pool of worker threads, reporting each result as it completes.
+///
+/// Workers stop picking up new projects once `cancel` is set; projects already
+/// being scanned run to completion.
+pub fn scan_targets_parallel<F>(
+    targets: &[ScanTarget],
+    starred: &HashSet<String>,
+    index: &ProjectIndexService,
+    full: bool,
+    cancel: &AtomicBool,
+    on_result: F,
+) where
+    F: Fn(&ScanTarget, Result<Project, String>) + Sync,
+{
+    let workers = thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(4)
+        .min(targets.len());
+    let next = AtomicUsize::new(0);
+
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| loop {
+                if cancel.load(Ordering::SeqCst) {
+                    break;
+                }
+                let i = next.fetch_add(1, Ordering::SeqCst);
+                let Some(target) = targets.get(i) else {
+                    break;
+                };
+                on_result(target, scan_project_indexed(&target.path, starred, index, full));
+            });
+        }
+    });
+}
+
+/// Start a background workspace scan and return its scan id.
+///
+/// Emits `project_scan_result` for every scanned project, `project_scan_progress`
+/// after each one, and `project_scan_finished` once the scan ends or is cancelled.
+#[tauri::command]
+pub fn start_project_scan(
+    app_handle: AppHandle,
+    base_dir: String,
+    full: Option<bool>,
+    registry: State<'_, ScanRegistry>,
+) -> Result<String, String> {
+    let scan_id = Uuid::new_v4().to_string();
+    let cancel = registry.register(&scan_id);
+    let full = full.unwrap_or(false);
+    let id = scan_id.clone();
+
+    thread::spawn(move || {
+        let max_depth = workspace::discovery_depth(&app_handle);
+        let finished = run_scan(&app_handle, &id, &base_dir, max_depth, full, &cancel);
+        app_handle.state::<ScanRegistry>().finish(&id);
+        let _ = app_handle.emit("project_scan_finished", &finished);
+    });
+
+    Ok(scan_id)
+}
+
+#[tauri::command]
+pub fn cancel_project_scan(scan_id: String, registry: State<'_, ScanRegistry>) -> Result<(), String> {
+    if registry.cancel(&scan_id) {
+        Ok(())
+    } else {
+        Err(format!("No scan in progress with id {}", scan_id))
+    }
+}
+
+fn run_scan(
+    app_handle: &AppHandle,
+    scan_id: &str,
+    base_dir: &str,
+    max_depth: usize,
+    full: bool,
+    cancel: &AtomicBool,
+) -> ScanFinished {
+    let mut finished = ScanFinished {
+        scan_id: scan_id.to_string(),
+        completed: 0,
+        total: 0,
+        cancelled: false,
+        categories: Vec::new(),
+        errors: Vec::new(),
+    };
+
+    let (categories, targets) = match collect_scan_targets(Path::new(base_dir), max_depth) {
+        Ok(found) => found,
+        Err(e) => {
+            finished.errors.push(e);
+            return finished;
+        }
+    };
+    finished.categories = categories;
+    finished.total = targets.len();
+
+    let _ = app_handle.emit(
+        "project_scan_progress",
+        &ScanProgress {
+            scan_id: scan_id.to_string(),
+            completed: 0,
+            total: targets.len(),
+            failed: 0,
+        },
+    );
+
+    let index = app_handle.state::<ProjectIndexService>();
+    let starred = load_starred_projects().unwrap_or_default();
+    let completed = AtomicUsize::new(0);
+    let errors = Mutex::new(Vec::new());
+
+    scan_targets_parallel(&targets, &starred, &index, full, cancel, |target, result| {
+        match result {
+            Ok(project) => {
+                let _ = app_handle.emit(
+                    "project_scan_result",
+                    &ScanProjectResult {
+                        scan_id: scan_id.to_string(),
+                        category: target.category.clone(),
+                        project,
+                    },
+                );
+            }
+            Err(e) => errors.lock().unwrap().push(e),
+        }
+        let done = completed.fetch_add(1, Ordering::SeqCst) + 1;
+        let _ = app_handle.emit(
+            "project_scan_progress",
+            &ScanProgress {
+                scan_id: scan_id.to_string(),
+                completed: done,
+                total: targets.len(),
+                failed: errors.lock().unwrap().len(),
+            },
+        );
+    });
+
+    finished.completed = completed.load(Ordering::SeqCst);
+    finished.cancelled = cancel.load(Ordering::SeqCst);
+    finished.errors = errors.into_inner().unwrap();
+
+    // Only prune the index when every project was visited
+    if !finished.cancelled && finished.errors.is_empty() {
+        let _ = index.retain(
+            base_dir,
+            &targets
+                .iter()
+                .map(|t| t.path.to_string_lossy().to_string())
+                .collect(),
+        );
+    }
+
+    finished
+}
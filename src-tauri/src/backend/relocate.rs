@@ -0,0 +1,138 @@
+use crate::backend::project_index_service::ProjectIndexService;
+use crate::backend::timeline::{TimelineEvent, TimelineEventType};
+use crate::backend::timeline_service::TimelineService;
+use crate::backend::{get_or_create_project_uuid, relocate_starred_projects, watcher};
+use chrono::Utc;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use tauri::{AppHandle, Emitter, Manager};
+use uuid::Uuid;
+
+fn validate_name(name: &str) -> Result<(), String> {
+    let name = name.trim();
+    if name.is_empty() || name == "." || name == ".." || name.contains(['/', '\\']) {
+        return Err(format!("Invalid project name: '{}'", name));
+    }
+    Ok(())
+}
+
+/// Resolve a category like `web-apps` or `clients/acme` inside the workspace
+pub(crate) fn category_dir(base_dir: &Path, category: &str) -> Result<PathBuf, String> {
+    let relative = Path::new(category);
+    if relative
+        .components()
+        .any(|c| !matches!(c, Component::Normal(_)))
+    {
+        return Err(format!("Invalid category: '{}'", category));
+    }
+    Ok(base_dir.join(relative))
+}
+
+/// Re-key every piece of state stored by project path: starred projects, watches and the scan index.
+///
+/// `to: None` forgets the project, for when it leaves the workspace.
+pub(crate) fn relocate_path_state(app_handle: &AppHandle, from: &Path, to: Option<&Path>) -> Result<(), String> {
+    relocate_starred_projects(from, to)?;
+    watcher::relocate_project_watch(app_handle, from, to)?;
+    if let Some(index) = app_handle.try_state::<ProjectIndexService>() {
+        index
+            .remove_under(&from.to_string_lossy())
+            .map_err(|e| format!("Failed to update project index: {}", e))?;
+    }
+    Ok(())
+}
+
+/// Move a project directory in one rename, keeping its `.project_id` and with it the timeline and tasks
+fn move_project_dir(app_handle: &AppHandle, from: &Path, to: &Path) -> Result<Uuid, String> {
+    if !from.is_dir() {
+        return Err(format!("Project directory not found: {}", from.display()));
+    }
+    if to.exists() {
+        return Err(format!("A project already exists at {}", to.display()));
+    }
+    if to.starts_with(from) {
+        return Err("Can't move a project into itself".to_string());
+    }
+    let project_id = Uuid::parse_str(&get_or_create_project_uuid(from.to_string_lossy().to_string())?)
+        .map_err(|e| e.to_string())?;
+
+    if let Some(parent) = to.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create category directory: {}", e))?;
+    }
+    watcher::suspend_project_watch(app_handle, &from.to_string_lossy());
+    if let Err(e) = fs::rename(from, to) {
+        let _ = watcher::relocate_project_watch(app_handle, from, Some(from));
+        return Err(format!("Failed to move project: {}", e));
+    }
+    relocate_path_state(app_handle, from, Some(to))?;
+    Ok(project_id)
+}
+
+fn record_move(app_handle: &AppHandle, project_id: Uuid, from: &Path, to: &Path, title: String, tag: &str) {
+    let event = TimelineEvent {
+        id: Uuid::new_v4(),
+        project_id,
+        timestamp: Utc::now(),
+        event_type: TimelineEventType::ProjectMoved {
+            from: from.to_string_lossy().to_string(),
+            to: to.to_string_lossy().to_string(),
+        },
+        title,
+        description: Some(format!("Moved from {} to {}", from.display(), to.display())),
+        metadata: HashMap::new(),
+        user_id: None,
+        tags: vec![tag.to_string()],
+    };
+
+    let _ = app_handle.emit("timeline_event_added", &event);
+    if let Some(timeline_service) = app_handle.try_state::<TimelineService>() {
+        let _ = timeline_service.add_event(&event);
+    }
+}
+
+/// Rename a project in place; returns the new project path
+#[tauri::command]
+pub fn rename_project(app_handle: AppHandle, project_path: String, new_name: String) -> Result<String, String> {
+    validate_name(&new_name)?;
+    let from = PathBuf::from(&project_path);
+    let old_name = from.file_name().unwrap_or_default().to_string_lossy().to_string();
+    let to = from.with_file_name(new_name.trim());
+
+    let project_id = move_project_dir(&app_handle, &from, &to)?;
+    record_move(
+        &app_handle,
+        project_id,
+        &from,
+        &to,
+        format!("Project renamed: {} → {}", old_name, new_name.trim()),
+        "renamed",
+    );
+    Ok(to.to_string_lossy().to_string())
+}
+
+/// Move a project into another category of the workspace; returns the new project path
+#[tauri::command]
+pub fn move_project(
+    app_handle: AppHandle,
+    base_dir: String,
+    project_path: String,
+    category: String,
+) -> Result<String, String> {
+    let from = PathBuf::from(&project_path);
+    let name = from
+        .file_name()
+        .ok_or_else(|| format!("Invalid project path: {}", project_path))?;
+    let to = category_dir(Path::new(&base_dir), &category)?.join(name);
+
+    let project_id = move_project_dir(&app_handle, &from, &to)?;
+    record_move(
+        &app_handle,
+        project_id,
+        &from,
+        &to,
+        format!("Project moved to {}", category),
+        "moved",
+    );
+    Ok(to.to_string_lossy().to_string())
+}
@@ -0,0 +1,190 @@
+use crate::backend::git::{self, GitError, user_name};
+use crate::backend::get_or_create_project_uuid;
+use crate::backend::timeline::{TimelineEvent, TimelineEventType};
+use crate::backend::timeline_service::TimelineService;
+use crate::backend::templates::substitute;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use tauri::{AppHandle, Emitter, Manager};
+use uuid::Uuid;
+
+const INITIAL_COMMIT_MESSAGE: &str = "Initial commit";
+
+/// License texts with `{{year}}` and `{{author}}` placeholders
+const LICENSES: &[(&str, &str)] = &[
+    ("MIT", include_str!("licenses/MIT.txt")),
+    ("ISC", include_str!("licenses/ISC.txt")),
+    ("BSD-3-Clause", include_str!("licenses/BSD-3-Clause.txt")),
+    ("Unlicense", include_str!("licenses/Unlicense.txt")),
+];
+
+/// Entries every generated .gitignore starts with
+const COMMON_IGNORES: &[&str] = &[".DS_Store", "Thumbs.db", ".env", ".env.local", ".project.toml"];
+
+/// Extra .gitignore entries per project type
+const TYPE_IGNORES: &[(&str, &[&str])] = &[
+    ("rust", &["/target"]),
+    ("leptos", &["/target"]),
+    ("yew", &["/target", "/dist"]),
+    ("sycamore", &["/target", "/dist"]),
+    ("tauri", &["node_modules/", "dist/", "src-tauri/target/", "src-tauri/gen/"]),
+    ("node", &["node_modules/", "npm-debug.log*", "yarn-error.log*", "coverage/"]),
+    ("react", &["node_modules/", "dist/", "build/", "coverage/"]),
+    ("preact", &["node_modules/", "dist/"]),
+    ("solid", &["node_modules/", "dist/"]),
+    ("vue", &["node_modules/", "dist/"]),
+    ("svelte", &["node_modules/", ".svelte-kit/", "build/"]),
+    ("angular", &["node_modules/", "dist/", ".angular/"]),
+    ("next", &["node_modules/", ".next/", "out/", "next-env.d.ts"]),
+    ("electron", &["node_modules/", "dist/", "out/"]),
+    ("vite", &["node_modules/", "dist/"]),
+    ("python", &["__pycache__/", "*.py[cod]", ".venv/", "venv/", "*.egg-info/", "dist/", "build/", ".pytest_cache/", ".mypy_cache/"]),
+    ("go", &["/bin/", "*.exe", "*.test", "*.out"]),
+    ("java", &["target/", "build/", ".gradle/", "*.class"]),
+    ("dotnet", &["bin/", "obj/", "*.user"]),
+    ("blazor", &["bin/", "obj/", "*.user"]),
+    ("dart", &[".dart_tool/", "build/", ".packages"]),
+    ("php", &["vendor/"]),
+    ("ruby", &[".bundle/", "vendor/bundle/", "log/", "tmp/"]),
+];
+
+/// Repository setup applied to a newly created project
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RepositoryOptions {
+    #[serde(default)]
+    pub git_init: bool,
+    /// Write a .gitignore for the project type, merged into any existing one
+    #[serde(default)]
+    pub gitignore: bool,
+    /// SPDX id of a license to write to LICENSE, e.g. `MIT`
+    #[serde(default)]
+    pub license: Option<String>,
+    /// Copyright holder for the license; defaults to git's `user.name`
+    #[serde(default)]
+    pub author: Option<String>,
+    /// Commit everything once the project is set up; requires `git_init`
+    #[serde(default)]
+    pub initial_commit: bool,
+}
+
+/// SPDX ids of the licenses that can be generated
+pub fn available_licenses() -> Vec<String> {
+    LICENSES.iter().map(|(id, _)| id.to_string()).collect()
+}
+
+/// .gitignore contents for a project type
+pub fn gitignore_for(project_type: &str) -> Vec<String> {
+    let mut entries = COMMON_IGNORES.iter().map(|e| e.to_string()).collect::<Vec<_>>();
+    if let Some((_, extra)) = TYPE_IGNORES.iter().find(|(t, _)| *t == project_type) {
+        for entry in extra.iter() {
+            if !entries.iter().any(|e| e == entry) {
+                entries.push(entry.to_string());
+            }
+        }
+    }
+    entries
+}
+
+fn write_gitignore(project_path: &Path, project_type: &str) -> Result<(), String> {
+    let path = project_path.join(".gitignore");
+    let mut contents = fs::read_to_string(&path).unwrap_or_default();
+    let existing = contents.lines().map(|l| l.trim().to_string()).collect::<Vec<_>>();
+    for entry in gitignore_for(project_type) {
+        if !existing.contains(&entry) {
+            if !contents.is_empty() && !contents.ends_with('\n') {
+                contents.push('\n');
+            }
+            contents.push_str(&entry);
+            contents.push('\n');
+        }
+    }
+    fs::write(&path, contents).map_err(|e| format!("Failed to write .gitignore: {}", e))
+}
+
+fn write_license(project_path: &Path, license: &str, author: Option<&str>) -> Result<(), String> {
+    let (_, text) = LICENSES
+        .iter()
+        .find(|(id, _)| id.eq_ignore_ascii_case(license))
+        .ok_or_else(|| format!("Unsupported license: {}", license))?;
+    let author = match author.filter(|a| !a.trim().is_empty()) {
+        Some(author) => author.to_string(),
+        None => user_name(project_path).unwrap_or_default(),
+    };
+    let values = HashMap::from([
+        ("year".to_string(), Utc::now().format("%Y").to_string()),
+        ("author".to_string(), author),
+    ]);
+    fs::write(project_path.join("LICENSE"), substitute(text, &values))
+        .map_err(|e| format!("Failed to write LICENSE: {}", e))
+}
+
+/// Check the options can be applied before the project directory is created
+pub fn check_options(options: &RepositoryOptions) -> Result<(), GitError> {
+    if options.git_init && options.initial_commit {
+        git::check_commit_identity()?;
+    }
+    Ok(())
+}
+
+/// Write the .gitignore and LICENSE the options ask for
+pub fn write_repository_files(
+    project_path: &Path,
+    project_type: &str,
+    options: &RepositoryOptions,
+) -> Result<(), String> {
+    if options.gitignore {
+        write_gitignore(project_path, project_type)?;
+    }
+    if let Some(license) = options.license.as_deref().filter(|l| !l.is_empty()) {
+        write_license(project_path, license, options.author.as_deref())?;
+    }
+    Ok(())
+}
+
+/// Create the repository the options ask for; returns the initial commit hash, if one was made
+pub fn initialize_repository(project_path: &Path, options: &RepositoryOptions) -> Result<Option<String>, GitError> {
+    if !options.git_init {
+        return Ok(None);
+    }
+    let repo = git::init_repository(project_path)?;
+    if !options.initial_commit {
+        return Ok(None);
+    }
+    git::commit_all(&repo, INITIAL_COMMIT_MESSAGE).map(Some)
+}
+
+/// Record the initial commit of a new project on its timeline
+pub fn record_initial_commit(app_handle: &AppHandle, project_path: &Path, hash: &str) {
+    let project_id = match get_or_create_project_uuid(project_path.to_string_lossy().to_string()) {
+        Ok(id) => Uuid::parse_str(&id).unwrap_or_else(|_| Uuid::new_v4()),
+        Err(_) => Uuid::new_v4(),
+    };
+
+    let event = TimelineEvent {
+        id: Uuid::new_v4(),
+        project_id,
+        timestamp: Utc::now(),
+        event_type: TimelineEventType::GitCommit {
+            hash: hash.to_string(),
+            message: INITIAL_COMMIT_MESSAGE.to_string(),
+        },
+        title: format!("Commit {}", &hash[..7.min(hash.len())]),
+        description: Some(INITIAL_COMMIT_MESSAGE.to_string()),
+        metadata: HashMap::new(),
+        user_id: None,
+        tags: vec!["commit".to_string()],
+    };
+
+    let _ = app_handle.emit("timeline_event_added", &event);
+    if let Some(timeline_service) = app_handle.try_state::<TimelineService>() {
+        let _ = timeline_service.add_event(&event);
+    }
+}
+
+#[tauri::command]
+pub fn get_available_licenses() -> Vec<String> {
+    available_licenses()
+}
@@ -0,0 +1,245 @@
+use crate::backend::record_project_created;
+use crate::backend::repo_init::{self, RepositoryOptions};
+use crate::backend::templates::slugify;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::process::Command;
+use tauri::AppHandle;
+
+/// Options passed through to the scaffolder
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ScaffoldOptions {
+    /// `cargo`: create a library instead of a binary
+    #[serde(default)]
+    pub lib: bool,
+    /// `go`: module path, defaults to the project's slug
+    #[serde(default)]
+    pub module: Option<String>,
+}
+
+/// One command run by a scaffolder, with its captured output
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScaffoldStep {
+    pub command: String,
+    pub exit_code: Option<i32>,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScaffoldResult {
+    pub project_path: String,
+    pub project_type: String,
+    pub steps: Vec<ScaffoldStep>,
+    pub initial_commit: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ScaffoldErrorKind {
+    AlreadyExists,
+    UnknownScaffolder,
+    ToolNotFound,
+    CommandFailed,
+    Repository,
+    Io,
+}
+
+/// Why scaffolding failed; `step` holds the output of the command that failed, if any
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScaffoldError {
+    pub kind: ScaffoldErrorKind,
+    pub message: String,
+    pub step: Option<ScaffoldStep>,
+}
+
+impl ScaffoldError {
+    fn new(kind: ScaffoldErrorKind, message: String) -> Self {
+        Self { kind, message, step: None }
+    }
+}
+
+/// Scaffolders offered by the create dialog, as (id, project type)
+pub const SCAFFOLDERS: &[(&str, &str)] = &[
+    ("cargo", "rust"),
+    ("npm", "node"),
+    ("go", "go"),
+    ("uv", "python"),
+    ("venv", "python"),
+];
+
+/// Steps to run inside the new project directory. Each step lists alternative
+/// commands, so a missing `python3` can fall back to `python`.
+fn scaffold_commands(scaffolder: &str, name: &str, options: &ScaffoldOptions) -> Option<Vec<Vec<Vec<String>>>> {
+    let slug = slugify(name);
+    let args = |items: &[&str]| items.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+    let commands = match scaffolder {
+        "cargo" => {
+            let mut cmd = args(&["cargo", "init", "--vcs", "none", "--name", &slug]);
+            if options.lib {
+                cmd.push("--lib".to_string());
+            }
+            vec![vec![cmd]]
+        }
+        "npm" => vec![vec![args(&["npm", "init", "-y"])]],
+        "go" => {
+            let module = options.module.clone().unwrap_or_else(|| slug.clone());
+            vec![vec![args(&["go", "mod", "init", &module])]]
+        }
+        "uv" => vec![vec![args(&["uv", "init", "--offline", "--vcs", "none", "--name", &slug])]],
+        "venv" => vec![vec![
+            args(&["python3", "-m", "venv", ".venv"]),
+            args(&["python", "-m", "venv", ".venv"]),
+        ]],
+        _ => return None,
+    };
+    Some(commands)
+}
+
+fn run_step(dir: &Path, command: &[String]) -> Result<ScaffoldStep, (ErrorKind, String)> {
+    let output = Command::new(&command[0])
+        .args(&command[1..])
+        .current_dir(dir)
+        .output()
+        .map_err(|e| (e.kind(), format!("Failed to run {}: {}", command[0], e)))?;
+    Ok(ScaffoldStep {
+        command: command.join(" "),
+        exit_code: output.status.code(),
+        stdout: String::from_utf8_lossy(&output.stdout).to_string(),
+        stderr: String::from_utf8_lossy(&output.stderr).to_string(),
+    })
+}
+
+fn run_scaffolder(dir: &Path, commands: &[Vec<Vec<String>>]) -> Result<Vec<ScaffoldStep>, ScaffoldError> {
+    let mut steps = Vec::new();
+    for alternatives in commands {
+        let mut last_error = None;
+        let mut step = None;
+        for command in alternatives {
+            match run_step(dir, command) {
+                Ok(result) => {
+                    step = Some(result);
+                    break;
+                }
+                Err((ErrorKind::NotFound, message)) => {
+                    last_error = Some(ScaffoldError::new(ScaffoldErrorKind::ToolNotFound, message));
+                }
+                Err((_, message)) => return Err(ScaffoldError::new(ScaffoldErrorKind::Io, message)),
+            }
+        }
+        let step = match step {
+            Some(step) => step,
+            None => {
+                return Err(last_error.unwrap_or_else(|| {
+                    ScaffoldError::new(ScaffoldErrorKind::Io, "No scaffold command to run".to_string())
+                }))
+            }
+        };
+        if step.exit_code != Some(0) {
+            return Err(ScaffoldError {
+                kind: ScaffoldErrorKind::CommandFailed,
+                message: format!(
+                    "{} failed: {}",
+                    step.command,
+                    step.stderr.lines().last().unwrap_or("").trim()
+                ),
+                step: Some(step),
+            });
+        }
+        steps.push(step);
+    }
+    Ok(steps)
+}
+
+/// Create `base_dir/category/name` with an ecosystem scaffolder; the directory is removed again on failure
+pub fn scaffold(
+    base_dir: &Path,
+    category: &str,
+    name: &str,
+    scaffolder: &str,
+    options: &ScaffoldOptions,
+    repository: &RepositoryOptions,
+) -> Result<ScaffoldResult, ScaffoldError> {
+    let project_type = SCAFFOLDERS
+        .iter()
+        .find(|(id, _)| *id == scaffolder)
+        .map(|(_, project_type)| project_type.to_string())
+        .ok_or_else(|| {
+            ScaffoldError::new(
+                ScaffoldErrorKind::UnknownScaffolder,
+                format!("Unknown scaffolder: {}", scaffolder),
+            )
+        })?;
+    let commands = scaffold_commands(scaffolder, name, options).unwrap_or_default();
+
+    let project_path = base_dir.join(category).join(name);
+    if project_path.exists() {
+        return Err(ScaffoldError::new(
+            ScaffoldErrorKind::AlreadyExists,
+            format!("Project '{}' already exists in category '{}'", name, category),
+        ));
+    }
+    repo_init::check_options(repository)
+        .map_err(|e| ScaffoldError::new(ScaffoldErrorKind::Repository, e.to_string()))?;
+    fs::create_dir_all(&project_path).map_err(|e| {
+        ScaffoldError::new(
+            ScaffoldErrorKind::Io,
+            format!("Failed to create project directory: {}", e),
+        )
+    })?;
+
+    let result = run_scaffolder(&project_path, &commands).and_then(|steps| {
+        repo_init::write_repository_files(&project_path, &project_type, repository)
+            .map_err(|e| ScaffoldError::new(ScaffoldErrorKind::Repository, e))?;
+        let initial_commit = repo_init::initialize_repository(&project_path, repository)
+            .map_err(|e| ScaffoldError::new(ScaffoldErrorKind::Repository, e.to_string()))?;
+        Ok(ScaffoldResult {
+            project_path: project_path.to_string_lossy().to_string(),
+            project_type: project_type.clone(),
+            steps,
+            initial_commit,
+        })
+    });
+    match result {
+        Ok(result) => Ok(result),
+        Err(e) => {
+            let _ = fs::remove_dir_all(&project_path);
+            Err(e)
+        }
+    }
+}
+
+/// Create a project with the ecosystem's own generator (`cargo`, `npm`, `go`, `uv` or `venv`)
+#[tauri::command]
+pub fn scaffold_project(
+    base_dir: String,
+    category: String,
+    name: String,
+    scaffolder: String,
+    options: Option<ScaffoldOptions>,
+    repository: Option<RepositoryOptions>,
+    app_handle: AppHandle,
+) -> Result<ScaffoldResult, ScaffoldError> {
+    let options = options.unwrap_or_default();
+    let repository = repository.unwrap_or_default();
+    let result = scaffold(
+        Path::new(&base_dir),
+        &category,
+        &name,
+        &scaffolder,
+        &options,
+        &repository,
+    )?;
+    record_project_created(
+        &app_handle,
+        Path::new(&result.project_path),
+        &name,
+        &category,
+        &result.project_type,
+    );
+    if let Some(hash) = &result.initial_commit {
+        repo_init::record_initial_commit(&app_handle, Path::new(&result.project_path), hash);
+    }
+    Ok(result)
+}
@@ -0,0 +1,552 @@
+use crate::backend::git;
+use crate::backend::ignore_rules::project_walker;
+use crate::backend::project_detect::{self, wildcard_match};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Manifest file at the root of every template directory
+pub const TEMPLATE_MANIFEST: &str = "template.toml";
+/// Subdirectory holding the files copied into a new project
+pub const TEMPLATE_FILES_DIR: &str = "files";
+/// Suffix stripped from file names on instantiation, so template manifests aren't picked up by tooling
+const TEMPLATE_SUFFIX: &str = ".tmpl";
+/// What `{{` in a saved project file is stored as
+const ESCAPED_OPEN: &str = r#"{{"{{"}}"#;
+
+/// Templates shipped with the app, seeded into the templates directory on first use
+const BUILTIN_TEMPLATES: &[(&str, &[(&str, &str)])] = &[
+    (
+        "blank",
+        &[
+            ("template.toml", include_str!("templates/blank/template.toml")),
+            ("files/README.md", include_str!("templates/blank/files/README.md")),
+        ],
+    ),
+    (
+        "rust",
+        &[
+            ("template.toml", include_str!("templates/rust/template.toml")),
+            ("files/Cargo.toml.tmpl", include_str!("templates/rust/files/Cargo.toml.tmpl")),
+            ("files/README.md", include_str!("templates/rust/files/README.md")),
+            ("files/src/main.rs", include_str!("templates/rust/files/src/main.rs")),
+            ("files/src/lib.rs", include_str!("templates/rust/files/src/lib.rs")),
+        ],
+    ),
+    (
+        "node",
+        &[
+            ("template.toml", include_str!("templates/node/template.toml")),
+            ("files/package.json.tmpl", include_str!("templates/node/files/package.json.tmpl")),
+            ("files/README.md", include_str!("templates/node/files/README.md")),
+            ("files/index.js", include_str!("templates/node/files/index.js")),
+        ],
+    ),
+    (
+        "react",
+        &[
+            ("template.toml", include_str!("templates/react/template.toml")),
+            ("files/package.json.tmpl", include_str!("templates/react/files/package.json.tmpl")),
+            ("files/index.html", include_str!("templates/react/files/index.html")),
+            ("files/vite.config.js", include_str!("templates/react/files/vite.config.js")),
+            ("files/src/main.jsx", include_str!("templates/react/files/src/main.jsx")),
+            ("files/src/App.jsx", include_str!("templates/react/files/src/App.jsx")),
+        ],
+    ),
+    (
+        "next",
+        &[
+            ("template.toml", include_str!("templates/next/template.toml")),
+            ("files/package.json.tmpl", include_str!("templates/next/files/package.json.tmpl")),
+            ("files/app/layout.js", include_str!("templates/next/files/app/layout.js")),
+            ("files/app/page.js", include_str!("templates/next/files/app/page.js")),
+        ],
+    ),
+];
+
+/// A value the user is prompted for when instantiating a template
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemplateVariable {
+    pub name: String,
+    #[serde(default)]
+    pub prompt: String,
+    /// May reference other variables, e.g. `{{git_author}}`
+    #[serde(default)]
+    pub default: Option<String>,
+    #[serde(default)]
+    pub required: bool,
+    /// Allowed values; empty means free text
+    #[serde(default)]
+    pub choices: Vec<String>,
+}
+
+/// Files only created when `when` holds: `var`, `!var`, `var == value` or `var != value`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConditionalFiles {
+    /// Paths relative to the template's files, `*` and `?` allowed
+    pub paths: Vec<String>,
+    pub when: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectTemplate {
+    /// Directory name under the templates directory
+    #[serde(default)]
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// Type recorded for projects created from this template
+    #[serde(default)]
+    pub project_type: String,
+    #[serde(default, alias = "variable")]
+    pub variables: Vec<TemplateVariable>,
+    #[serde(default, alias = "conditional")]
+    pub conditionals: Vec<ConditionalFiles>,
+    #[serde(default)]
+    pub builtin: bool,
+}
+
+pub fn templates_dir() -> Result<PathBuf, String> {
+    let mut path = dirs::data_dir().ok_or("Could not find data directory")?;
+    path.push("project-manager");
+    path.push("templates");
+    fs::create_dir_all(&path).map_err(|e| format!("Failed to create templates dir: {}", e))?;
+    Ok(path)
+}
+
+/// Write any built-in template that is missing; existing ones are left as the user edited them
+fn ensure_builtin_templates(dir: &Path) -> Result<(), String> {
+    for (id, files) in BUILTIN_TEMPLATES {
+        let template_dir = dir.join(id);
+        if template_dir.exists() {
+            continue;
+        }
+        for (relative, contents) in files.iter() {
+            let path = template_dir.join(relative);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)
+                    .map_err(|e| format!("Failed to create template dir: {}", e))?;
+            }
+            fs::write(&path, contents)
+                .map_err(|e| format!("Failed to write template file: {}", e))?;
+        }
+    }
+    Ok(())
+}
+
+fn read_template(dir: &Path) -> Result<ProjectTemplate, String> {
+    let id = dir.file_name().unwrap_or_default().to_string_lossy().to_string();
+    let data = fs::read_to_string(dir.join(TEMPLATE_MANIFEST))
+        .map_err(|e| format!("Failed to read template '{}': {}", id, e))?;
+    let mut template = toml::from_str::<ProjectTemplate>(&data)
+        .map_err(|e| format!("Failed to parse template '{}': {}", id, e))?;
+    template.builtin = BUILTIN_TEMPLATES.iter().any(|(builtin, _)| *builtin == id);
+    template.id = id;
+    Ok(template)
+}
+
+/// A literal string in a saved project that becomes a template variable
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemplateLiteral {
+    pub literal: String,
+    /// Variable name; implicit ones like `name` or `name_slug` aren't declared in the manifest
+    pub name: String,
+    #[serde(default)]
+    pub prompt: Option<String>,
+}
+
+/// Files never carried over when saving a project as a template
+const TEMPLATE_EXCLUDED_FILES: &[&str] = &[".project_id", ".project.toml"];
+
+fn is_valid_template_id(id: &str) -> bool {
+    !id.is_empty()
+        && !id.starts_with('.')
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.')
+}
+
+/// Every template in the templates directory, sorted by name
+pub fn list_templates() -> Result<Vec<ProjectTemplate>, String> {
+    let dir = templates_dir()?;
+    ensure_builtin_templates(&dir)?;
+
+    let mut templates = fs::read_dir(&dir)
+        .map_err(|e| format!("Failed to read templates dir: {}", e))?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.join(TEMPLATE_MANIFEST).is_file())
+        .filter(|p| !p.file_name().unwrap_or_default().to_string_lossy().starts_with('.'))
+        .filter_map(|p| read_template(&p).ok())
+        .collect::<Vec<_>>();
+    templates.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+    Ok(templates)
+}
+
+pub fn load_template(id: &str) -> Result<ProjectTemplate, String> {
+    if !is_valid_template_id(id) {
+        return Err(format!("Invalid template id: {}", id));
+    }
+    let dir = templates_dir()?;
+    ensure_builtin_templates(&dir)?;
+    let template_dir = dir.join(id);
+    if !template_dir.join(TEMPLATE_MANIFEST).is_file() {
+        return Err(format!("Template '{}' not found", id));
+    }
+    read_template(&template_dir)
+}
+
+/// Lowercase the name and collapse anything outside `[a-z0-9_]` into single dashes
+pub(crate) fn slugify(name: &str) -> String {
+    let mut slug = String::new();
+    for c in name.trim().chars() {
+        if c.is_ascii_alphanumeric() || c == '_' {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.ends_with('-') && !slug.is_empty() {
+            slug.push('-');
+        }
+    }
+    slug.trim_end_matches('-').to_string()
+}
+
+/// Variables every template can use without declaring them
+fn implicit_variables(project_name: &str, project_type: &str) -> HashMap<String, String> {
+    let now = chrono::Local::now();
+    let git_author = git::global_user_name().unwrap_or_default();
+    HashMap::from([
+        ("name".to_string(), project_name.to_string()),
+        ("name_slug".to_string(), slugify(project_name)),
+        ("project_type".to_string(), project_type.to_string()),
+        ("year".to_string(), now.format("%Y").to_string()),
+        ("date".to_string(), now.format("%Y-%m-%d").to_string()),
+        ("git_author".to_string(), git_author),
+    ])
+}
+
+/// Merge the provided values with defaults and validate them against the template
+pub fn resolve_variables(
+    template: &ProjectTemplate,
+    project_name: &str,
+    provided: &HashMap<String, String>,
+) -> Result<HashMap<String, String>, String> {
+    let project_type = if template.project_type.is_empty() {
+        provided.get("project_type").cloned().unwrap_or_else(|| template.id.clone())
+    } else {
+        template.project_type.clone()
+    };
+    let mut values = implicit_variables(project_name, &project_type);
+
+    for variable in &template.variables {
+        let value = match provided.get(&variable.name) {
+            Some(value) => value.clone(),
+            None => variable
+                .default
+                .as_deref()
+                .map(|d| substitute(d, &values))
+                .unwrap_or_default(),
+        };
+        if variable.required && value.trim().is_empty() {
+            return Err(format!("Template variable '{}' is required", variable.name));
+        }
+        if !variable.choices.is_empty() && !variable.choices.contains(&value) {
+            return Err(format!(
+                "Invalid value '{}' for template variable '{}', expected one of: {}",
+                value,
+                variable.name,
+                variable.choices.join(", ")
+            ));
+        }
+        values.insert(variable.name.clone(), value);
+    }
+    Ok(values)
+}
+
+/// Replace `{{ var }}` placeholders for known variables; anything else is left untouched.
+///
+/// A quoted placeholder like `{{"{{"}}` produces its text verbatim, which is how
+/// saved templates keep the project's own `{{ ... }}` from being substituted.
+pub fn substitute(text: &str, values: &HashMap<String, String>) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        match after.find("}}") {
+            Some(end) => {
+                let key = after[..end].trim();
+                match quoted_literal(key).or_else(|| values.get(key).map(String::as_str)) {
+                    Some(value) => {
+                        out.push_str(value);
+                        rest = &after[end + 2..];
+                    }
+                    None => {
+                        out.push_str("{{");
+                        rest = after;
+                    }
+                }
+            }
+            None => {
+                out.push_str(&rest[start..]);
+                rest = "";
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+fn quoted_literal(key: &str) -> Option<&str> {
+    key.strip_prefix('"')?.strip_suffix('"')
+}
+
+/// Escape every `{{` already in a project file so `substitute` reproduces it unchanged
+fn escape_placeholders(text: &str) -> String {
+    text.replace("{{", ESCAPED_OPEN)
+}
+
+fn is_truthy(value: Option<&String>) -> bool {
+    match value.map(|v| v.trim().to_lowercase()) {
+        Some(v) => !matches!(v.as_str(), "" | "false" | "no" | "0" | "none"),
+        None => false,
+    }
+}
+
+fn condition_holds(expr: &str, values: &HashMap<String, String>) -> bool {
+    let lookup = |name: &str| values.get(name.trim()).map(|v| v.as_str()).unwrap_or("");
+    if let Some((name, expected)) = expr.split_once("!=") {
+        lookup(name) != expected.trim()
+    } else if let Some((name, expected)) = expr.split_once("==") {
+        lookup(name) == expected.trim()
+    } else if let Some(name) = expr.trim().strip_prefix('!') {
+        !is_truthy(values.get(name.trim()))
+    } else {
+        is_truthy(values.get(expr.trim()))
+    }
+}
+
+fn is_included(relative: &str, template: &ProjectTemplate, values: &HashMap<String, String>) -> bool {
+    template
+        .conditionals
+        .iter()
+        .filter(|c| c.paths.iter().any(|p| p == relative || wildcard_match(p, relative)))
+        .all(|c| condition_holds(&c.when, values))
+}
+
+/// Copy a template's files into `dest`, substituting names and contents; returns the files written
+pub fn instantiate(
+    template: &ProjectTemplate,
+    dest: &Path,
+    values: &HashMap<String, String>,
+) -> Result<Vec<PathBuf>, String> {
+    let files_root = templates_dir()?.join(&template.id).join(TEMPLATE_FILES_DIR);
+    let mut written = Vec::new();
+    if !files_root.is_dir() {
+        return Ok(written);
+    }
+
+    let mut stack = vec![files_root.clone()];
+    while let Some(dir) = stack.pop() {
+        let entries = fs::read_dir(&dir).map_err(|e| format!("Failed to read template dir: {}", e))?;
+        for entry in entries.filter_map(|e| e.ok()) {
+            let path = entry.path();
+            let relative = path
+                .strip_prefix(&files_root)
+                .unwrap_or(&path)
+                .to_string_lossy()
+                .replace('\\', "/");
+            if path.is_dir() {
+                stack.push(path);
+                continue;
+            }
+            if !is_included(&relative, template, values) {
+                continue;
+            }
+
+            let target_relative = substitute(&relative, values);
+            let target_relative = target_relative
+                .strip_suffix(TEMPLATE_SUFFIX)
+                .unwrap_or(&target_relative);
+            let target = dest.join(target_relative);
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent)
+                    .map_err(|e| format!("Failed to create directory: {}", e))?;
+            }
+
+            let bytes = fs::read(&path).map_err(|e| format!("Failed to read {}: {}", relative, e))?;
+            // Binary files are copied verbatim
+            let contents = match String::from_utf8(bytes) {
+                Ok(text) => substitute(&text, values).into_bytes(),
+                Err(e) => e.into_bytes(),
+            };
+            fs::write(&target, contents)
+                .map_err(|e| format!("Failed to write {}: {}", target_relative, e))?;
+            written.push(target);
+        }
+    }
+    Ok(written)
+}
+
+fn replace_literals(text: &str, literals: &[TemplateLiteral]) -> String {
+    let mut text = text.to_string();
+    for literal in literals {
+        text = text.replace(&literal.literal, &format!("{{{{{}}}}}", literal.name));
+    }
+    text
+}
+
+/// Copy a project into a new template, turning the given literals into variables
+pub fn save_as_template(
+    project_path: &Path,
+    id: &str,
+    name: &str,
+    description: &str,
+    literals: &[TemplateLiteral],
+    overwrite: bool,
+) -> Result<ProjectTemplate, String> {
+    if !is_valid_template_id(id) {
+        return Err(format!("Invalid template id: {}", id));
+    }
+    if !project_path.is_dir() {
+        return Err(format!("Project directory not found: {}", project_path.display()));
+    }
+    if BUILTIN_TEMPLATES.iter().any(|(builtin, _)| *builtin == id) {
+        return Err(format!("Template '{}' is built in and can't be replaced", id));
+    }
+    let dir = templates_dir()?;
+    let target = dir.join(id);
+    if target.exists() && !overwrite {
+        return Err(format!("Template '{}' already exists", id));
+    }
+
+    // Longest first, so a literal that contains another one wins
+    let mut literals = literals
+        .iter()
+        .filter(|l| !l.literal.is_empty())
+        .cloned()
+        .collect::<Vec<_>>();
+    literals.sort_by(|a, b| b.literal.len().cmp(&a.literal.len()));
+
+    let implicit = implicit_variables("", "");
+    let mut variables: Vec<TemplateVariable> = Vec::new();
+    for literal in &literals {
+        if implicit.contains_key(&literal.name) || variables.iter().any(|v| v.name == literal.name) {
+            continue;
+        }
+        variables.push(TemplateVariable {
+            name: literal.name.clone(),
+            prompt: literal.prompt.clone().unwrap_or_else(|| literal.name.clone()),
+            default: Some(literal.literal.clone()),
+            required: false,
+            choices: Vec::new(),
+        });
+    }
+    let template = ProjectTemplate {
+        id: id.to_string(),
+        name: name.to_string(),
+        description: description.to_string(),
+        project_type: project_detect::registry().detect(project_path).primary,
+        variables,
+        conditionals: Vec::new(),
+        builtin: false,
+    };
+
+    // Build next to the final location and swap it in once complete
+    let staging = dir.join(format!(".{}.staging", id));
+    if staging.exists() {
+        fs::remove_dir_all(&staging).map_err(|e| format!("Failed to clear staging dir: {}", e))?;
+    }
+    let result = write_template(&staging, project_path, &template, &literals).and_then(|_| {
+        if target.exists() {
+            fs::remove_dir_all(&target).map_err(|e| format!("Failed to replace template: {}", e))?;
+        }
+        fs::rename(&staging, &target).map_err(|e| format!("Failed to register template: {}", e))
+    });
+    if let Err(e) = result {
+        let _ = fs::remove_dir_all(&staging);
+        return Err(e);
+    }
+
+    read_template(&target)
+}
+
+fn write_template(
+    staging: &Path,
+    project_path: &Path,
+    template: &ProjectTemplate,
+    literals: &[TemplateLiteral],
+) -> Result<(), String> {
+    let files_root = staging.join(TEMPLATE_FILES_DIR);
+    fs::create_dir_all(&files_root).map_err(|e| format!("Failed to create template dir: {}", e))?;
+
+    for entry in project_walker(project_path).build().filter_map(|e| e.ok()) {
+        if !entry.file_type().map(|t| t.is_file()).unwrap_or(false) {
+            continue;
+        }
+        let path = entry.path();
+        if TEMPLATE_EXCLUDED_FILES
+            .iter()
+            .any(|excluded| path.file_name().map(|n| n == *excluded).unwrap_or(false))
+        {
+            continue;
+        }
+        let relative = path
+            .strip_prefix(project_path)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .replace('\\', "/");
+        let mut target_relative = replace_literals(&relative, literals);
+        // Keep real `.tmpl` files from losing their suffix on instantiation
+        if target_relative.ends_with(TEMPLATE_SUFFIX) {
+            target_relative.push_str(TEMPLATE_SUFFIX);
+        }
+        let target = files_root.join(&target_relative);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent).map_err(|e| format!("Failed to create directory: {}", e))?;
+        }
+
+        let bytes = fs::read(path).map_err(|e| format!("Failed to read {}: {}", relative, e))?;
+        let contents = match String::from_utf8(bytes) {
+            Ok(text) => replace_literals(&escape_placeholders(&text), literals).into_bytes(),
+            Err(e) => e.into_bytes(),
+        };
+        fs::write(&target, contents).map_err(|e| format!("Failed to write {}: {}", target_relative, e))?;
+    }
+
+    // `id` and `builtin` are derived when the template is read back
+    let mut manifest = toml::Value::try_from(template)
+        .map_err(|e| format!("Failed to serialize template: {}", e))?;
+    if let Some(table) = manifest.as_table_mut() {
+        table.remove("id");
+        table.remove("builtin");
+    }
+    let manifest = toml::to_string_pretty(&manifest)
+        .map_err(|e| format!("Failed to serialize template: {}", e))?;
+    fs::write(staging.join(TEMPLATE_MANIFEST), manifest)
+        .map_err(|e| format!("Failed to write template manifest: {}", e))
+}
+
+#[tauri::command]
+pub fn list_project_templates() -> Result<Vec<ProjectTemplate>, String> {
+    list_templates()
+}
+
+#[tauri::command]
+pub fn save_project_as_template(
+    project_path: String,
+    template_id: String,
+    name: String,
+    description: Option<String>,
+    variables: Vec<TemplateLiteral>,
+    overwrite: Option<bool>,
+) -> Result<ProjectTemplate, String> {
+    save_as_template(
+        Path::new(&project_path),
+        &template_id,
+        &name,
+        &description.unwrap_or_default(),
+        &variables,
+        overwrite.unwrap_or(false),
+    )
+}
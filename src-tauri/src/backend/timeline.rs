@@ -24,6 +24,9 @@ pub enum TimelineEventType {
     FileAdded { path: String, size: u64 },
     FileModified { path: String },
     GitCommit { hash: String, message: String },
+    ProjectMoved { from: String, to: String },
+    ProjectArchived { archive: String },
+    ProjectRestored { path: String },
     BuildCompleted { status: String },
     Custom { event_name: String },
 }
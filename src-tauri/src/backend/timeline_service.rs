@@ -3,6 +3,11 @@ use rusqlite::{params, Connection, Result as SqlResult};
 use std::sync::{Arc, Mutex};
 use uuid::Uuid;
 use chrono::{DateTime, Utc};
+use std::collections::HashSet;
+
+const INSERT_EVENT: &str =
+    "INSERT INTO timeline_events (id, project_id, timestamp, event_type, title, description, metadata, user_id, tags)
+    VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)";
 
 pub struct TimelineService {
     db: Arc<Mutex<Connection>>,
@@ -24,29 +29,78 @@ impl TimelineService {
                 tags TEXT
             );
         "#).unwrap();
+        // Databases created before soft deletion lack the column; the error for an existing one is expected
+        let _ = conn.execute("ALTER TABLE timeline_events ADD COLUMN deleted_at TEXT", []);
         Self { db: Arc::new(Mutex::new(conn)) }
     }
 
     pub fn add_event(&self, event: &TimelineEvent) -> SqlResult<()> {
         let db = self.db.lock().unwrap();
-        db.execute(
-            "INSERT INTO timeline_events (id, project_id, timestamp, event_type, title, description, metadata, user_id, tags)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
-            params![
-                event.id.to_string(),
-                event.project_id.to_string(),
-                event.timestamp.to_rfc3339(),
-                serde_json::to_string(&event.event_type).unwrap(),
-                event.title,
-                event.description,
-                serde_json::to_string(&event.metadata).unwrap(),
-                event.user_id,
-                serde_json::to_string(&event.tags).unwrap(),
-            ]
-        )?;
+        db.execute(INSERT_EVENT, Self::event_params(event))?;
         Ok(())
     }
 
+    /// Insert many events in one transaction
+    pub fn add_events(&self, events: &[TimelineEvent]) -> SqlResult<()> {
+        let mut db = self.db.lock().unwrap();
+        let tx = db.transaction()?;
+        {
+            let mut stmt = tx.prepare(INSERT_EVENT)?;
+            for event in events {
+                stmt.execute(Self::event_params(event))?;
+            }
+        }
+        tx.commit()
+    }
+
+    fn event_params(event: &TimelineEvent) -> [Box<dyn rusqlite::ToSql>; 9] {
+        [
+            Box::new(event.id.to_string()),
+            Box::new(event.project_id.to_string()),
+            Box::new(event.timestamp.to_rfc3339()),
+            Box::new(serde_json::to_string(&event.event_type).unwrap()),
+            Box::new(event.title.clone()),
+            Box::new(event.description.clone()),
+            Box::new(serde_json::to_string(&event.metadata).unwrap()),
+            Box::new(event.user_id.clone()),
+            Box::new(serde_json::to_string(&event.tags).unwrap()),
+        ]
+    }
+
+    /// Hashes of every commit already on a project's timeline
+    pub fn commit_hashes(&self, project_id: Uuid) -> SqlResult<HashSet<String>> {
+        let db = self.db.lock().unwrap();
+        let mut stmt = db.prepare(
+            "SELECT event_type FROM timeline_events WHERE project_id = ?1 AND event_type LIKE '{\"GitCommit\"%'",
+        )?;
+        let rows = stmt.query_map(params![project_id.to_string()], |row| row.get::<_, String>(0))?;
+        let mut hashes = HashSet::new();
+        for event_type in rows {
+            if let Ok(TimelineEventType::GitCommit { hash, .. }) = serde_json::from_str(&event_type?) {
+                hashes.insert(hash);
+            }
+        }
+        Ok(hashes)
+    }
+
+    /// Hide or unhide every event of a project that was moved to the trash
+    pub fn set_project_deleted(&self, project_id: Uuid, deleted: bool) -> SqlResult<usize> {
+        let db = self.db.lock().unwrap();
+        let deleted_at = deleted.then(|| Utc::now().to_rfc3339());
+        db.execute(
+            "UPDATE timeline_events SET deleted_at = ?2 WHERE project_id = ?1",
+            params![project_id.to_string(), deleted_at],
+        )
+    }
+
+    pub fn purge_project(&self, project_id: Uuid) -> SqlResult<usize> {
+        let db = self.db.lock().unwrap();
+        db.execute(
+            "DELETE FROM timeline_events WHERE project_id = ?1",
+            params![project_id.to_string()],
+        )
+    }
+
     pub fn get_project_timeline(
         &self,
         project_id: Uuid,
@@ -56,7 +110,7 @@ impl TimelineService {
         event_types: Option<Vec<String>>,
     ) -> SqlResult<Vec<TimelineEvent>> {
         let db = self.db.lock().unwrap();
-        let mut query = String::from("SELECT * FROM timeline_events WHERE project_id = ?1");
+        let mut query = String::from("SELECT * FROM timeline_events WHERE project_id = ?1 AND deleted_at IS NULL");
         let mut params_vec: Vec<Box<dyn rusqlite::ToSql>> = vec![Box::new(project_id.to_string())];
         let mut param_index = 2;
         if let Some(ref s) = search {
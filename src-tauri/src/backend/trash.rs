@@ -0,0 +1,309 @@
+use crate::backend::get_or_create_project_uuid;
+use crate::backend::kanban_service::KanbanService;
+use crate::backend::relocate::{category_dir, relocate_path_state};
+use crate::backend::timeline::{TimelineEvent, TimelineEventType};
+use crate::backend::timeline_service::TimelineService;
+use crate::backend::trash_service::{TrashService, TrashedProject};
+use crate::backend::watcher;
+use crate::workspace;
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Emitter, Manager};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TrashListing {
+    pub projects: Vec<TrashedProject>,
+    pub purge_errors: Vec<String>,
+}
+
+pub fn trash_dir() -> Result<PathBuf, String> {
+    let mut path = dirs::data_dir().ok_or("Could not find data directory")?;
+    path.push("project-manager");
+    path.push("trash");
+    fs::create_dir_all(&path).map_err(|e| format!("Failed to create trash dir: {}", e))?;
+    Ok(path)
+}
+
+/// Copy `from` into `to`, leaving files that already exist in `to` alone
+fn copy_tree(from: &Path, to: &Path) -> std::io::Result<()> {
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        let file_type = entry.file_type()?;
+        if !file_type.is_dir() && target.symlink_metadata().is_ok() {
+            continue;
+        }
+        if file_type.is_dir() {
+            copy_tree(&entry.path(), &target)?;
+        } else if file_type.is_symlink() {
+            #[cfg(unix)]
+            std::os::unix::fs::symlink(fs::read_link(entry.path())?, &target)?;
+            #[cfg(not(unix))]
+            fs::copy(entry.path(), &target).map(|_| ())?;
+        } else {
+            fs::copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
+
+/// Rename a directory, falling back to copy-then-remove when the trash lives on another drive
+fn move_dir(from: &Path, to: &Path) -> Result<(), String> {
+    if fs::rename(from, to).is_ok() {
+        return Ok(());
+    }
+    if let Err(e) = copy_tree(from, to) {
+        let _ = fs::remove_dir_all(to);
+        return Err(format!("Failed to move {}: {}", from.display(), e));
+    }
+    if let Err(e) = fs::remove_dir_all(from) {
+        // Put back whatever was already removed, so only one copy is left
+        if copy_tree(to, from).is_ok() {
+            let _ = fs::remove_dir_all(to);
+            return Err(format!("Failed to remove {}: {}", from.display(), e));
+        }
+        return Err(format!(
+            "Failed to remove {}: {}; a complete copy is left at {}",
+            from.display(),
+            e,
+            to.display()
+        ));
+    }
+    Ok(())
+}
+
+fn set_records_deleted(app_handle: &AppHandle, project_id: Uuid, deleted: bool) -> Result<(), String> {
+    if let Some(timeline) = app_handle.try_state::<TimelineService>() {
+        timeline
+            .set_project_deleted(project_id, deleted)
+            .map_err(|e| format!("Failed to update timeline: {}", e))?;
+    }
+    if let Some(kanban) = app_handle.try_state::<KanbanService>() {
+        kanban
+            .set_project_deleted(project_id, deleted)
+            .map_err(|e| format!("Failed to update tasks: {}", e))?;
+    }
+    Ok(())
+}
+
+fn purge_after(deleted_at: &str, retention_days: u32) -> String {
+    let deleted_at = DateTime::parse_from_rfc3339(deleted_at)
+        .map(|d| d.with_timezone(&Utc))
+        .unwrap_or_else(|_| Utc::now());
+    (deleted_at + Duration::days(retention_days as i64)).to_rfc3339()
+}
+
+fn delete(app_handle: &AppHandle, project_path: &Path) -> Result<TrashedProject, String> {
+    if !project_path.is_dir() {
+        return Err(format!("Project directory not found: {}", project_path.display()));
+    }
+    let project_id = get_or_create_project_uuid(project_path.to_string_lossy().to_string())?;
+    let uuid = Uuid::parse_str(&project_id).map_err(|e| e.to_string())?;
+
+    // Copies of a project share its id; never let one overwrite another's trash entry
+    let trash_path = trash_dir()?.join(&project_id);
+    let service = app_handle.state::<TrashService>();
+    let already_trashed = service
+        .get(&project_id)
+        .map_err(|e| format!("Failed to read trash: {}", e))?
+        .is_some();
+    if already_trashed || trash_path.exists() {
+        return Err(format!(
+            "A project with the same id ({}) is already in the trash; restore or purge it first",
+            project_id
+        ));
+    }
+
+    let deleted_at = Utc::now().to_rfc3339();
+    let trashed = TrashedProject {
+        project_id: project_id.clone(),
+        name: project_path.file_name().unwrap_or_default().to_string_lossy().to_string(),
+        original_path: project_path.to_string_lossy().to_string(),
+        trash_path: trash_path.to_string_lossy().to_string(),
+        purge_after: purge_after(&deleted_at, workspace::trash_retention_days(app_handle)),
+        deleted_at,
+    };
+    // Record the entry before moving, so files in the trash always have a row to restore or purge them by
+    service
+        .add(&trashed)
+        .map_err(|e| format!("Failed to record deleted project: {}", e))?;
+    watcher::suspend_project_watch(app_handle, &project_path.to_string_lossy());
+    if let Err(e) = move_dir(project_path, &trash_path) {
+        let _ = watcher::relocate_project_watch(app_handle, project_path, Some(project_path));
+        if !trash_path.exists() {
+            let _ = service.remove(&project_id);
+        }
+        return Err(e);
+    }
+    set_records_deleted(app_handle, uuid, true)?;
+    relocate_path_state(app_handle, project_path, None)?;
+    Ok(trashed)
+}
+
+fn restore(app_handle: &AppHandle, project_id: &str, base_dir: &Path, category: Option<&str>) -> Result<String, String> {
+    let service = app_handle.state::<TrashService>();
+    let trashed = service
+        .get(project_id)
+        .map_err(|e| format!("Failed to read trash: {}", e))?
+        .ok_or_else(|| format!("Project {} is not in the trash", project_id))?;
+    let uuid = Uuid::parse_str(project_id).map_err(|e| e.to_string())?;
+
+    let target = match category {
+        Some(category) => category_dir(base_dir, category)?.join(&trashed.name),
+        None => PathBuf::from(&trashed.original_path),
+    };
+    if target.exists() {
+        return Err(format!("A project already exists at {}", target.display()));
+    }
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create category directory: {}", e))?;
+    }
+    move_dir(Path::new(&trashed.trash_path), &target)?;
+
+    service
+        .remove(project_id)
+        .map_err(|e| format!("Failed to update trash: {}", e))?;
+    set_records_deleted(app_handle, uuid, false)?;
+
+    let restored_path = target.to_string_lossy().to_string();
+    let event = TimelineEvent {
+        id: Uuid::new_v4(),
+        project_id: uuid,
+        timestamp: Utc::now(),
+        event_type: TimelineEventType::ProjectRestored {
+            path: restored_path.clone(),
+        },
+        title: format!("Project restored from trash: {}", trashed.name),
+        description: None,
+        metadata: HashMap::new(),
+        user_id: None,
+        tags: vec!["undeleted".to_string()],
+    };
+    let _ = app_handle.emit("timeline_event_added", &event);
+    if let Some(timeline_service) = app_handle.try_state::<TimelineService>() {
+        let _ = timeline_service.add_event(&event);
+    }
+    Ok(restored_path)
+}
+
+/// Permanently remove a trashed project's files and its timeline and task records
+fn purge(app_handle: &AppHandle, trashed: &TrashedProject) -> Result<(), String> {
+    let trash_path = Path::new(&trashed.trash_path);
+    if trash_path.exists() {
+        fs::remove_dir_all(trash_path).map_err(|e| format!("Failed to purge {}: {}", trashed.name, e))?;
+    }
+    if let Ok(uuid) = Uuid::parse_str(&trashed.project_id) {
+        if let Some(timeline) = app_handle.try_state::<TimelineService>() {
+            timeline
+                .purge_project(uuid)
+                .map_err(|e| format!("Failed to purge timeline: {}", e))?;
+        }
+        if let Some(kanban) = app_handle.try_state::<KanbanService>() {
+            kanban
+                .purge_project(uuid)
+                .map_err(|e| format!("Failed to purge tasks: {}", e))?;
+        }
+    }
+    app_handle
+        .state::<TrashService>()
+        .remove(&trashed.project_id)
+        .map_err(|e| format!("Failed to update trash: {}", e))
+}
+
+/// Purge every trashed project past its retention period.
+///
+/// Returns how many were purged and an error for each one that couldn't be; those stay in the trash and are retried next time.
+pub fn purge_expired_trash(app_handle: &AppHandle) -> Result<(usize, Vec<String>), String> {
+    let now = Utc::now();
+    let trashed = app_handle
+        .state::<TrashService>()
+        .list()
+        .map_err(|e| format!("Failed to read trash: {}", e))?;
+    let mut purged = 0;
+    let mut errors = Vec::new();
+    for entry in trashed {
+        let expired = DateTime::parse_from_rfc3339(&entry.purge_after)
+            .map(|d| d.with_timezone(&Utc) <= now)
+            .unwrap_or(false);
+        if !expired {
+            continue;
+        }
+        match purge(app_handle, &entry) {
+            Ok(()) => purged += 1,
+            Err(e) => errors.push(e),
+        }
+    }
+    Ok((purged, errors))
+}
+
+/// Move a project to the trash and hide its timeline and tasks until it is restored or purged
+#[tauri::command]
+pub async fn delete_project(app_handle: AppHandle, project_path: String) -> Result<TrashedProject, String> {
+    tauri::async_runtime::spawn_blocking(move || delete(&app_handle, Path::new(&project_path)))
+        .await
+        .map_err(|e| format!("Delete task failed: {}", e))?
+}
+
+/// Undo a deletion, into the original location or into `category` when given
+#[tauri::command]
+pub async fn restore_deleted_project(
+    app_handle: AppHandle,
+    project_id: String,
+    base_dir: String,
+    category: Option<String>,
+) -> Result<String, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        restore(&app_handle, &project_id, Path::new(&base_dir), category.as_deref())
+    })
+    .await
+    .map_err(|e| format!("Restore task failed: {}", e))?
+}
+
+/// Trashed projects, after purging expired ones; `purge_errors` lists expired projects that couldn't be purged
+#[tauri::command]
+pub fn list_trashed_projects(app_handle: AppHandle) -> Result<TrashListing, String> {
+    let (_, purge_errors) = purge_expired_trash(&app_handle)?;
+    let projects = app_handle
+        .state::<TrashService>()
+        .list()
+        .map_err(|e| format!("Failed to read trash: {}", e))?;
+    Ok(TrashListing { projects, purge_errors })
+}
+
+/// Permanently delete a trashed project without waiting for its retention period
+#[tauri::command]
+pub async fn purge_deleted_project(app_handle: AppHandle, project_id: String) -> Result<(), String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let trashed = app_handle
+            .state::<TrashService>()
+            .get(&project_id)
+            .map_err(|e| format!("Failed to read trash: {}", e))?
+            .ok_or_else(|| format!("Project {} is not in the trash", project_id))?;
+        purge(&app_handle, &trashed)
+    })
+    .await
+    .map_err(|e| format!("Purge task failed: {}", e))?
+}
+
+/// Change how long deleted projects are kept; applies to projects already in the trash
+#[tauri::command]
+pub fn set_trash_retention_days(app_handle: AppHandle, days: u32) -> Result<(), String> {
+    let base_dir = workspace::ensure_workspace(&app_handle)?;
+    let mut cfg = workspace::WorkspaceConfig::load(&app_handle)
+        .unwrap_or_else(|| workspace::WorkspaceConfig::new(base_dir));
+    cfg.trash_retention_days = days;
+    cfg.save(&app_handle).map_err(|e| e.to_string())?;
+
+    let service = app_handle.state::<TrashService>();
+    for entry in service.list().map_err(|e| format!("Failed to read trash: {}", e))? {
+        service
+            .set_purge_after(&entry.project_id, &purge_after(&entry.deleted_at, days))
+            .map_err(|e| format!("Failed to update trash: {}", e))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use rusqlite::{params, Connection, OptionalExtension, Result as SqlResult, Row};
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
+
+/// A deleted project waiting in the trash until `purge_after`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrashedProject {
+    pub project_id: String,
+    pub name: String,
+    pub original_path: String,
+    pub trash_path: String,
+    pub deleted_at: String,
+    pub purge_after: String,
+}
+
+pub struct TrashService {
+    db: Arc<Mutex<Connection>>,
+}
+
+impl TrashService {
+    pub fn new(db_path: &str) -> Self {
+        let conn = Connection::open(db_path).expect("Failed to open trash DB");
+        conn.execute_batch(r#"
+            CREATE TABLE IF NOT EXISTS trashed_projects (
+                project_id TEXT PRIMARY KEY,
+                name TEXT NOT NULL,
+                original_path TEXT NOT NULL,
+                trash_path TEXT NOT NULL,
+                deleted_at TEXT NOT NULL,
+                purge_after TEXT NOT NULL
+            );
+        "#).unwrap();
+        Self { db: Arc::new(Mutex::new(conn)) }
+    }
+
+    fn from_row(row: &Row) -> SqlResult<TrashedProject> {
+        Ok(TrashedProject {
+            project_id: row.get(0)?,
+            name: row.get(1)?,
+            original_path: row.get(2)?,
+            trash_path: row.get(3)?,
+            deleted_at: row.get(4)?,
+            purge_after: row.get(5)?,
+        })
+    }
+
+    pub fn add(&self, project: &TrashedProject) -> SqlResult<()> {
+        let db = self.db.lock().unwrap();
+        db.execute(
+            "INSERT INTO trashed_projects (project_id, name, original_path, trash_path, deleted_at, purge_after)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                project.project_id,
+                project.name,
+                project.original_path,
+                project.trash_path,
+                project.deleted_at,
+                project.purge_after,
+            ]
+        )?;
+        Ok(())
+    }
+
+    pub fn get(&self, project_id: &str) -> SqlResult<Option<TrashedProject>> {
+        let db = self.db.lock().unwrap();
+        db.query_row(
+            "SELECT project_id, name, original_path, trash_path, deleted_at, purge_after
+            FROM trashed_projects WHERE project_id = ?1",
+            params![project_id],
+            Self::from_row,
+        )
+        .optional()
+    }
+
+    pub fn list(&self) -> SqlResult<Vec<TrashedProject>> {
+        let db = self.db.lock().unwrap();
+        let mut stmt = db.prepare(
+            "SELECT project_id, name, original_path, trash_path, deleted_at, purge_after
+            FROM trashed_projects ORDER BY deleted_at DESC",
+        )?;
+        let rows = stmt.query_map([], Self::from_row)?;
+        rows.collect()
+    }
+
+    pub fn set_purge_after(&self, project_id: &str, purge_after: &str) -> SqlResult<()> {
+        let db = self.db.lock().unwrap();
+        db.execute(
+            "UPDATE trashed_projects SET purge_after = ?2 WHERE project_id = ?1",
+            params![project_id, purge_after],
+        )?;
+        Ok(())
+    }
+
+    pub fn remove(&self, project_id: &str) -> SqlResult<()> {
+        let db = self.db.lock().unwrap();
+        db.execute("DELETE FROM trashed_projects WHERE project_id = ?1", params![project_id])?;
+        Ok(())
+    }
+}
@@ -0,0 +1,351 @@
+use crate::backend::{get_or_create_project_uuid, rebase_path};
+use crate::backend::timeline::{TimelineEvent, TimelineEventType};
+use crate::backend::timeline_service::TimelineService;
+use chrono::Utc;
+use notify::event::{CreateKind, ModifyKind};
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use notify_debouncer_full::{new_debouncer, DebounceEventResult, DebouncedEvent, Debouncer, FileIdMap};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager, State};
+use uuid::Uuid;
+
+const DEBOUNCE_TIMEOUT: Duration = Duration::from_secs(2);
+/// Directory names whose contents never produce timeline events
+const IGNORED_DIRS: [&str; 3] = ["target", "node_modules", ".git"];
+/// Bookkeeping files written by the app itself
+const IGNORED_FILES: [&str; 2] = [".project_id", ".project.toml"];
+/// Above this many changed files in one batch, record a single ProjectModified event
+const MAX_FILE_EVENTS: usize = 25;
+
+/// Active filesystem watchers keyed by project path
+#[derive(Default)]
+pub struct WatcherService {
+    watchers: Mutex<HashMap<String, Debouncer<RecommendedWatcher, FileIdMap>>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum FileChange {
+    Added(u64),
+    Modified,
+    Structural,
+}
+
+impl WatcherService {
+    fn watch(&self, app_handle: &AppHandle, project_path: &str) -> Result<(), String> {
+        let mut watchers = self.watchers.lock().map_err(|_| "Failed to lock watchers".to_string())?;
+        if watchers.contains_key(project_path) {
+            return Ok(());
+        }
+
+        let root = PathBuf::from(project_path);
+        if !root.is_dir() {
+            return Err(format!("Project directory not found: {}", project_path));
+        }
+        let project_id = Uuid::parse_str(&get_or_create_project_uuid(project_path.to_string())?)
+            .map_err(|e| e.to_string())?;
+
+        let handle = app_handle.clone();
+        let handler_root = root.clone();
+        let handler_key = project_path.to_string();
+        let mut debouncer = new_debouncer(DEBOUNCE_TIMEOUT, None, move |result: DebounceEventResult| {
+            if let Ok(events) = result {
+                record_changes(&handle, project_id, &handler_root, &events);
+                watch_new_dirs(&handle, &handler_key, &handler_root, &events);
+            }
+        })
+        .map_err(|e| format!("Failed to create watcher: {}", e))?;
+
+        add_watches(&mut debouncer, &root).map_err(|e| format!("Failed to watch {}: {}", project_path, e))?;
+
+        watchers.insert(project_path.to_string(), debouncer);
+        Ok(())
+    }
+
+    fn unwatch(&self, project_path: &str) -> Result<(), String> {
+        let mut watchers = self.watchers.lock().map_err(|_| "Failed to lock watchers".to_string())?;
+        watchers.remove(project_path);
+        Ok(())
+    }
+}
+
+/// `dir` and every directory below it, except ignored ones and symlinks.
+///
+/// Each gets its own non-recursive watch so `node_modules`, `target` and `.git`
+/// never use up the system's watch limit.
+fn watchable_dirs(dir: &Path) -> Vec<PathBuf> {
+    let mut dirs = vec![dir.to_path_buf()];
+    let mut pending = vec![dir.to_path_buf()];
+    while let Some(current) = pending.pop() {
+        let Ok(entries) = fs::read_dir(&current) else {
+            continue;
+        };
+        for entry in entries.filter_map(|e| e.ok()) {
+            // `file_type` doesn't follow symlinks, so linked directories are skipped
+            let is_dir = entry.file_type().map(|t| t.is_dir()).unwrap_or(false);
+            let ignored = IGNORED_DIRS.contains(&entry.file_name().to_string_lossy().as_ref());
+            if is_dir && !ignored {
+                dirs.push(entry.path());
+                pending.push(entry.path());
+            }
+        }
+    }
+    dirs
+}
+
+fn add_watches(debouncer: &mut Debouncer<RecommendedWatcher, FileIdMap>, dir: &Path) -> notify::Result<()> {
+    for dir in watchable_dirs(dir) {
+        debouncer.watcher().watch(&dir, RecursiveMode::NonRecursive)?;
+        debouncer.cache().add_root(&dir, RecursiveMode::NonRecursive);
+    }
+    Ok(())
+}
+
+/// Start watching directories created or moved into the project since it was first watched
+fn watch_new_dirs(app_handle: &AppHandle, project_path: &str, root: &Path, events: &[DebouncedEvent]) {
+    let new_dirs = events
+        .iter()
+        .filter(|event| matches!(event.kind, EventKind::Create(_) | EventKind::Modify(ModifyKind::Name(_))))
+        .flat_map(|event| event.paths.iter())
+        .filter(|path| path.is_dir())
+        .filter(|path| {
+            path.strip_prefix(root)
+                .map(|relative| !relative.as_os_str().is_empty() && !is_ignored(relative))
+                .unwrap_or(false)
+        })
+        .collect::<Vec<_>>();
+    if new_dirs.is_empty() {
+        return;
+    }
+    let Some(service) = app_handle.try_state::<WatcherService>() else {
+        return;
+    };
+    let Ok(mut watchers) = service.watchers.lock() else {
+        return;
+    };
+    if let Some(debouncer) = watchers.get_mut(project_path) {
+        for dir in new_dirs {
+            let _ = add_watches(debouncer, dir);
+        }
+    }
+}
+
+fn is_ignored(relative: &Path) -> bool {
+    relative.components().any(|c| {
+        let name = c.as_os_str().to_string_lossy();
+        IGNORED_DIRS.contains(&name.as_ref())
+    }) || relative
+        .file_name()
+        .map(|n| IGNORED_FILES.contains(&n.to_string_lossy().as_ref()))
+        .unwrap_or(false)
+}
+
+/// Collapse a debounced batch into one change per relative path
+fn classify_changes(root: &Path, events: &[DebouncedEvent]) -> BTreeMap<String, FileChange> {
+    let mut changes = BTreeMap::new();
+    for event in events {
+        for path in &event.paths {
+            let Ok(relative) = path.strip_prefix(root) else {
+                continue;
+            };
+            if relative.as_os_str().is_empty() || is_ignored(relative) {
+                continue;
+            }
+            let is_file = path.is_file();
+            let change = match event.kind {
+                EventKind::Create(CreateKind::File) | EventKind::Create(CreateKind::Any) if is_file => {
+                    FileChange::Added(fs::metadata(path).map(|m| m.len()).unwrap_or(0))
+                }
+                EventKind::Modify(ModifyKind::Data(_)) | EventKind::Modify(ModifyKind::Any) if is_file => {
+                    FileChange::Modified
+                }
+                EventKind::Create(_) | EventKind::Remove(_) | EventKind::Modify(ModifyKind::Name(_)) => {
+                    FileChange::Structural
+                }
+                _ => continue,
+            };
+            let key = relative.to_string_lossy().to_string();
+            // A file created and then written in the same batch is still an addition
+            match (changes.get(&key), &change) {
+                (Some(FileChange::Added(_)), FileChange::Modified) => {}
+                _ => {
+                    changes.insert(key, change);
+                }
+            }
+        }
+    }
+    changes
+}
+
+fn record_changes(app_handle: &AppHandle, project_id: Uuid, root: &Path, events: &[DebouncedEvent]) {
+    let changes = classify_changes(root, events);
+    if changes.is_empty() {
+        return;
+    }
+
+    let project_name = root
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let file_changes = changes
+        .iter()
+        .filter(|(_, c)| **c != FileChange::Structural)
+        .count();
+
+    let mut timeline_events = Vec::new();
+    if file_changes > MAX_FILE_EVENTS || file_changes < changes.len() {
+        let mut metadata = HashMap::new();
+        metadata.insert("changed_paths".to_string(), serde_json::json!(changes.len()));
+        timeline_events.push(new_event(
+            project_id,
+            TimelineEventType::ProjectModified,
+            format!("Project modified: {}", project_name),
+            Some(format!("{} paths changed in '{}'.", changes.len(), project_name)),
+            metadata,
+        ));
+    }
+    if file_changes <= MAX_FILE_EVENTS {
+        for (path, change) in changes {
+            let (event_type, title) = match change {
+                FileChange::Added(size) => (
+                    TimelineEventType::FileAdded { path: path.clone(), size },
+                    format!("File added: {}", path),
+                ),
+                FileChange::Modified => (
+                    TimelineEventType::FileModified { path: path.clone() },
+                    format!("File modified: {}", path),
+                ),
+                FileChange::Structural => continue,
+            };
+            timeline_events.push(new_event(project_id, event_type, title, None, HashMap::new()));
+        }
+    }
+
+    let timeline_service = app_handle.try_state::<TimelineService>();
+    for event in timeline_events {
+        let _ = app_handle.emit("timeline_event_added", &event);
+        if let Some(service) = &timeline_service {
+            let _ = service.add_event(&event);
+        }
+    }
+}
+
+fn new_event(
+    project_id: Uuid,
+    event_type: TimelineEventType,
+    title: String,
+    description: Option<String>,
+    metadata: HashMap<String, serde_json::Value>,
+) -> TimelineEvent {
+    TimelineEvent {
+        id: Uuid::new_v4(),
+        project_id,
+        timestamp: Utc::now(),
+        event_type,
+        title,
+        description,
+        metadata,
+        user_id: None,
+        tags: vec!["watcher".to_string()],
+    }
+}
+
+fn watched_file_path() -> Result<PathBuf, String> {
+    let mut path = dirs::data_dir().ok_or("Could not find data directory")?;
+    path.push("project-manager");
+    fs::create_dir_all(&path).map_err(|e| format!("Failed to create data dir: {}", e))?;
+    path.push("watched.json");
+    Ok(path)
+}
+
+fn load_watched_projects() -> Result<HashSet<String>, String> {
+    let file = watched_file_path()?;
+    if let Ok(data) = fs::read_to_string(&file) {
+        Ok(serde_json::from_str(&data).unwrap_or_default())
+    } else {
+        Ok(HashSet::new())
+    }
+}
+
+fn save_watched_projects(set: &HashSet<String>) -> Result<(), String> {
+    let file = watched_file_path()?;
+    let data =
+        serde_json::to_string_pretty(set).map_err(|e| format!("Failed to serialize: {}", e))?;
+    fs::write(file, data).map_err(|e| format!("Failed to write watched file: {}", e))
+}
+
+/// Stop the live watcher for a project that is about to be moved or removed
+pub(crate) fn suspend_project_watch(app_handle: &AppHandle, project_path: &str) {
+    if let Some(service) = app_handle.try_state::<WatcherService>() {
+        let _ = service.unwatch(project_path);
+    }
+}
+
+/// Re-key persisted watches under `from` to `to`, or drop them when `to` is `None`, and resume watching
+pub(crate) fn relocate_project_watch(app_handle: &AppHandle, from: &Path, to: Option<&Path>) -> Result<(), String> {
+    let watched = load_watched_projects()?;
+    let mut updated = HashSet::new();
+    let mut resumed = Vec::new();
+    for project_path in watched.iter() {
+        if !Path::new(project_path).starts_with(from) {
+            updated.insert(project_path.clone());
+            continue;
+        }
+        if let Some(new_path) = to.and_then(|to| rebase_path(project_path, from, to)) {
+            updated.insert(new_path.clone());
+            resumed.push(new_path);
+        }
+    }
+    if updated != watched {
+        save_watched_projects(&updated)?;
+    }
+    if let Some(service) = app_handle.try_state::<WatcherService>() {
+        for project_path in resumed {
+            service.watch(app_handle, &project_path)?;
+        }
+    }
+    Ok(())
+}
+
+/// Re-enable watchers for every project that was watched when the app last ran
+pub fn restore_project_watches(app_handle: &AppHandle) {
+    let service = app_handle.state::<WatcherService>();
+    for project_path in load_watched_projects().unwrap_or_default() {
+        let _ = service.watch(app_handle, &project_path);
+    }
+}
+
+#[tauri::command]
+pub fn enable_project_watch(
+    app_handle: AppHandle,
+    project_path: String,
+    state: State<'_, WatcherService>,
+) -> Result<(), String> {
+    state.watch(&app_handle, &project_path)?;
+    let mut watched = load_watched_projects()?;
+    if watched.insert(project_path) {
+        save_watched_projects(&watched)?;
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub fn disable_project_watch(project_path: String, state: State<'_, WatcherService>) -> Result<(), String> {
+    state.unwatch(&project_path)?;
+    let mut watched = load_watched_projects()?;
+    if watched.remove(&project_path) {
+        save_watched_projects(&watched)?;
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub fn get_watched_projects(state: State<'_, WatcherService>) -> Result<Vec<String>, String> {
+    let watchers = state.watchers.lock().map_err(|_| "Failed to lock watchers".to_string())?;
+    let mut paths = watchers.keys().cloned().collect::<Vec<_>>();
+    paths.sort();
+    Ok(paths)
+}
@@ -0,0 +1,147 @@
+use crate::backend::project_detect::wildcard_match;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Manifests that make a workspace member directory a package
+const MEMBER_MANIFESTS: [&str; 2] = ["Cargo.toml", "package.json"];
+
+/// Member package directories declared by a Cargo, npm/yarn or pnpm workspace root
+pub fn member_paths(root: &Path) -> Vec<PathBuf> {
+    let mut includes = Vec::new();
+    let mut excludes = Vec::new();
+
+    if let Ok(data) = fs::read_to_string(root.join("Cargo.toml")) {
+        if let Ok(manifest) = toml::from_str::<toml::Table>(&data) {
+            if let Some(workspace) = manifest.get("workspace").and_then(|w| w.as_table()) {
+                includes.extend(string_array(workspace.get("members")));
+                excludes.extend(string_array(workspace.get("exclude")));
+            }
+        }
+    }
+
+    if let Ok(data) = fs::read_to_string(root.join("package.json")) {
+        if let Ok(json) = serde_json::from_str::<serde_json::Value>(&data) {
+            // Either `"workspaces": [..]` or yarn's `"workspaces": { "packages": [..] }`
+            let patterns = json["workspaces"]
+                .as_array()
+                .or_else(|| json["workspaces"]["packages"].as_array());
+            for pattern in patterns.into_iter().flatten().filter_map(|p| p.as_str()) {
+                match pattern.strip_prefix('!') {
+                    Some(negated) => excludes.push(negated.to_string()),
+                    None => includes.push(pattern.to_string()),
+                }
+            }
+        }
+    }
+
+    if let Ok(data) = fs::read_to_string(root.join("pnpm-workspace.yaml")) {
+        for pattern in pnpm_workspace_packages(&data) {
+            match pattern.strip_prefix('!') {
+                Some(negated) => excludes.push(negated.to_string()),
+                None => includes.push(pattern),
+            }
+        }
+    }
+
+    let excluded = excludes
+        .iter()
+        .flat_map(|pattern| expand_pattern(root, pattern))
+        .collect::<BTreeSet<_>>();
+    includes
+        .iter()
+        .flat_map(|pattern| expand_pattern(root, pattern))
+        .filter(|path| path != root && !excluded.contains(path))
+        .filter(|path| MEMBER_MANIFESTS.iter().any(|m| path.join(m).exists()))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+fn string_array(value: Option<&toml::Value>) -> Vec<String> {
+    value
+        .and_then(|v| v.as_array())
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| item.as_str().map(|s| s.to_string()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Read the `packages:` list of a pnpm-workspace.yaml without a YAML parser
+fn pnpm_workspace_packages(data: &str) -> Vec<String> {
+    let mut packages = Vec::new();
+    let mut in_packages = false;
+    for line in data.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        if !line.starts_with(' ') && !line.starts_with('-') {
+            in_packages = trimmed == "packages:";
+            continue;
+        }
+        if in_packages {
+            if let Some(item) = trimmed.strip_prefix('-') {
+                let item = item.split(" #").next().unwrap_or(item).trim();
+                packages.push(item.trim_matches(|c| c == '"' || c == '\'').to_string());
+            }
+        }
+    }
+    packages
+}
+
+/// Expand a workspace glob (`*` within a segment, `**` across segments) to existing directories
+fn expand_pattern(root: &Path, pattern: &str) -> Vec<PathBuf> {
+    fn expand(dir: &Path, segments: &[&str], out: &mut Vec<PathBuf>) {
+        let Some((segment, rest)) = segments.split_first() else {
+            if dir.is_dir() {
+                out.push(dir.to_path_buf());
+            }
+            return;
+        };
+        match *segment {
+            "" | "." => expand(dir, rest, out),
+            "**" => {
+                expand(dir, rest, out);
+                for child in child_dirs(dir) {
+                    expand(&child, segments, out);
+                }
+            }
+            s if s.contains(['*', '?']) => {
+                for child in child_dirs(dir) {
+                    let name = child.file_name().unwrap_or_default().to_string_lossy().to_string();
+                    if wildcard_match(s, &name) {
+                        expand(&child, rest, out);
+                    }
+                }
+            }
+            s => expand(&dir.join(s), rest, out),
+        }
+    }
+
+    fn child_dirs(dir: &Path) -> Vec<PathBuf> {
+        fs::read_dir(dir)
+            .map(|entries| {
+                entries
+                    .filter_map(|e| e.ok())
+                    .map(|e| e.path())
+                    .filter(|p| {
+                        p.is_dir()
+                            && !matches!(
+                                p.file_name().and_then(|n| n.to_str()),
+                                Some("node_modules" | "target" | ".git")
+                            )
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    let segments = pattern.trim_end_matches('/').split('/').collect::<Vec<_>>();
+    let mut out = Vec::new();
+    expand(root, &segments, &mut out);
+    out
+}
@@ -11,7 +11,12 @@ use app_state::AppState;
 use backend::*;
 
 fn main() {
-    use backend::{timeline_service::TimelineService, kanban_service::KanbanService};
+    use backend::{
+        archive_service::ArchiveService, commit_hook::CommitSpoolService, kanban_service::KanbanService,
+        project_index_service::ProjectIndexService, project_scan::ScanRegistry,
+        timeline_service::TimelineService, trash_service::TrashService,
+        watcher::WatcherService,
+    };
     use std::path::PathBuf;
     tauri::Builder::default()
         .manage(Mutex::new(AppState::default()))
@@ -29,6 +34,30 @@ fn main() {
                 .join("project-manager/kanban.sqlite");
             KanbanService::new(kanban_db_path.to_str().unwrap())
         })
+        .manage({
+            // Cache scanned projects so unchanged ones are not re-walked
+            let index_db_path = dirs::data_dir()
+                .unwrap_or_else(|| PathBuf::from("./"))
+                .join("project-manager/project_index.sqlite");
+            ProjectIndexService::new(index_db_path.to_str().unwrap())
+        })
+        .manage({
+            // Archived projects, so their timeline and tasks stay reachable
+            let archive_db_path = dirs::data_dir()
+                .unwrap_or_else(|| PathBuf::from("./"))
+                .join("project-manager/archive.sqlite");
+            ArchiveService::new(archive_db_path.to_str().unwrap())
+        })
+        .manage({
+            // Deleted projects waiting out their retention period
+            let trash_db_path = dirs::data_dir()
+                .unwrap_or_else(|| PathBuf::from("./"))
+                .join("project-manager/trash.sqlite");
+            TrashService::new(trash_db_path.to_str().unwrap())
+        })
+        .manage(ScanRegistry::default())
+        .manage(WatcherService::default())
+        .manage(CommitSpoolService::default())
         .setup(|app| {
             let handle = app.handle();
             let base = workspace::ensure_workspace(&handle)?;
@@ -38,12 +67,59 @@ fn main() {
                 let mut state = app_state.lock().unwrap();
                 state.base_dir = base_str;
             }
+            watcher::restore_project_watches(handle);
+            let _ = trash::purge_expired_trash(handle);
+            let _ = commit_hook::start_commit_spool(handle);
             Ok(())
         })
         .plugin(tauri_plugin_fs::init())
         .invoke_handler(tauri::generate_handler![
             initialize_workspace,
             scan_projects,
+            rescan_projects,
+            scan_project_tree,
+            set_discovery_depth,
+            project_scan::start_project_scan,
+            project_scan::cancel_project_scan,
+            project_detect::reload_project_type_rules,
+            languages::get_project_languages,
+            ignore_rules::get_directory_stats,
+            ignore_rules::get_global_ignore_patterns,
+            ignore_rules::set_global_ignore_patterns,
+            git::get_git_summary,
+            git::get_latest_commit_hash,
+            branches::list_branches,
+            branches::create_branch,
+            branches::switch_branch,
+            branches::delete_merged_branches,
+            diff::get_diff,
+            git_history::import_git_history,
+            commit_hook::install_commit_hook,
+            commit_hook::uninstall_commit_hook,
+            commit_hook::is_commit_hook_installed,
+            manifests::get_project_dependencies,
+            templates::list_project_templates,
+            templates::save_project_as_template,
+            scaffold::scaffold_project,
+            repo_init::get_available_licenses,
+            relocate::rename_project,
+            relocate::move_project,
+            archive::archive_project,
+            archive::restore_project,
+            archive::list_archived_projects,
+            trash::delete_project,
+            trash::restore_deleted_project,
+            trash::list_trashed_projects,
+            trash::purge_deleted_project,
+            trash::set_trash_retention_days,
+            clone::clone_project,
+            project_meta::get_project_metadata,
+            project_meta::update_project_metadata,
+            cleanup::find_build_artifacts,
+            cleanup::clean_build_artifacts,
+            watcher::enable_project_watch,
+            watcher::disable_project_watch,
+            watcher::get_watched_projects,
             create_project,
             open_project_in_editor,
             open_project_in_terminal,
@@ -6,12 +6,38 @@ use std::{
 use tauri::{AppHandle, Manager};
 use rfd::FileDialog;
 
+/// How many directory levels below a category may group projects
+pub const DEFAULT_DISCOVERY_DEPTH: usize = 3;
+
+/// Days a deleted project stays in the trash before it is purged
+pub const DEFAULT_TRASH_RETENTION_DAYS: u32 = 30;
+
+fn default_discovery_depth() -> usize {
+    DEFAULT_DISCOVERY_DEPTH
+}
+
+fn default_trash_retention_days() -> u32 {
+    DEFAULT_TRASH_RETENTION_DAYS
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct WorkspaceConfig {
     pub base_dir: PathBuf,
+    #[serde(default = "default_discovery_depth")]
+    pub discovery_depth: usize,
+    #[serde(default = "default_trash_retention_days")]
+    pub trash_retention_days: u32,
 }
 
 impl WorkspaceConfig {
+    pub fn new(base_dir: PathBuf) -> Self {
+        Self {
+            base_dir,
+            discovery_depth: DEFAULT_DISCOVERY_DEPTH,
+            trash_retention_days: DEFAULT_TRASH_RETENTION_DAYS,
+        }
+    }
+
     fn config_path(app: &AppHandle) -> Option<PathBuf> {
         app.path()
             .app_config_dir()
@@ -62,9 +88,19 @@ pub fn ensure_workspace(app: &AppHandle) -> Result<PathBuf, String> {
     });
 
     create_category_dirs(&base_dir).map_err(|e| e.to_string())?;
-    let cfg = WorkspaceConfig {
-        base_dir: base_dir.clone(),
-    };
+    let cfg = WorkspaceConfig::new(base_dir.clone());
     cfg.save(app).map_err(|e| e.to_string())?;
     Ok(base_dir)
 }
+
+pub fn discovery_depth(app: &AppHandle) -> usize {
+    WorkspaceConfig::load(app)
+        .map(|cfg| cfg.discovery_depth)
+        .unwrap_or(DEFAULT_DISCOVERY_DEPTH)
+}
+
+pub fn trash_retention_days(app: &AppHandle) -> u32 {
+    WorkspaceConfig::load(app)
+        .map(|cfg| cfg.trash_retention_days)
+        .unwrap_or(DEFAULT_TRASH_RETENTION_DAYS)
+}
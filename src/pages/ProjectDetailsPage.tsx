@@ -55,19 +55,21 @@ const ProjectTypeIcon = ({ type }: { type: string }) => {
   return icons[type] || icons.default;
 };
 
-const GitStatusIndicator = ({ status }: { status: string }) => {
+const GitStatusIndicator = ({ status, error }: { status: string; error?: string }) => {
   const statusMap = {
     'clean': { text: 'Clean', color: 'text-green-500' },
     'modified': { text: 'Modified', color: 'text-yellow-500' },
     'ahead': { text: 'Ahead', color: 'text-blue-500' },
     'behind': { text: 'Behind', color: 'text-orange-500' },
     'diverged': { text: 'Diverged', color: 'text-red-500' },
+    'none': { text: 'No repository', color: 'text-muted-foreground' },
+    'error': { text: 'Git error', color: 'text-red-500' },
   };
   
   const info = statusMap[status] || { text: status, color: 'text-muted-foreground' };
   
   return (
-    <div className="flex items-center gap-1">
+    <div className="flex items-center gap-1" title={error}>
       <GitBranch className={`w-3 h-3 ${info.color}`} />
       <span className={`text-xs ${info.color}`}>{info.text}</span>
     </div>
@@ -238,7 +240,7 @@ const ProjectDetailsPage: React.FC = () => {
             >
               {getProjectTypeLabel(project.project_type)}
             </Badge>
-            <GitStatusIndicator status={project.git_status} />
+            <GitStatusIndicator status={project.git_status} error={project.git?.error} />
           </div>
         </div>
         
@@ -351,7 +353,7 @@ const ProjectDetailsPage: React.FC = () => {
                   
                   <div className="text-center p-4 bg-muted rounded-lg">
                     <div className="text-2xl font-bold text-primary mb-1">
-                      {project.git_status === 'clean' ? '✓' : '!'}
+                      {project.git_status === 'clean' ? '✓' : project.git_status === 'none' ? '–' : '!'}
                     </div>
                     <div className="text-sm text-muted-foreground">Git Status</div>
                   </div>
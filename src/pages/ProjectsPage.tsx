@@ -151,7 +151,9 @@ const GitStatusIndicator = ({ status }) => {
     'modified': { color: 'text-yellow-500', label: 'Modified' },
     'ahead': { color: 'text-blue-500', label: 'Ahead' },
     'behind': { color: 'text-red-500', label: 'Behind' },
-    'diverged': { color: 'text-purple-500', label: 'Diverged' }
+    'diverged': { color: 'text-purple-500', label: 'Diverged' },
+    'none': { color: 'text-muted-foreground', label: 'No repository' },
+    'error': { color: 'text-red-500', label: 'Git error' }
   };
 
   const config = statusConfig[status] || statusConfig.clean;